@@ -5,23 +5,57 @@
 //! [`Client`]: struct.Client.html
 
 use std::{
+    cmp::Ordering,
+    collections::HashMap,
     convert::{TryFrom, TryInto},
     fmt,
+    sync::Arc,
 };
 
+use bytes::Bytes;
 use chrono::{DateTime, Duration, Utc};
-use futures::lock::Mutex;
-use reqwest::{header::HeaderValue, Client as HttpClient, Method, RequestBuilder, Response};
+use futures::{
+    lock::{Mutex, MutexGuard},
+    stream::{self, Stream, StreamExt, TryStreamExt},
+};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, AUTHORIZATION},
+    Client as HttpClient, Method, RequestBuilder, Response, StatusCode,
+};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use url::Url;
+use tokio::sync::Semaphore;
+use url::{ParseError as URLParseError, Url};
 
 use crate::error::{Error, Result};
 use crate::language::*;
 use crate::params::*;
 use crate::response::*;
 
+mod builder;
+mod retry;
+
+pub use builder::ClientBuilder;
+pub use retry::{RetryConfig, RetryStrategy};
+
+use retry::RetryHook;
+
 const BASE_URL: &str = "https://api.thetvdb.com/";
 const TOKEN_EXP_LIMIT: i64 = 60;
+const DEFAULT_LANG_ABBR: &str = "en";
+const DEFAULT_API_VERSION: &str = "3.0.0";
+// TheTVDB documents a 24 hour token lifetime; assume a bit less for tokens
+// whose expiry cannot be read
+const OPAQUE_TOKEN_LIFETIME: i64 = 23 * 60 * 60;
+// longest timespan the `/updated/query` endpoint accepts
+const UPDATED_MAX_DAYS: i64 = 7;
+// series requested at the same time by methods fetching many series
+const SERIES_MANY_CONCURRENCY: usize = 8;
+// week-long windows requested at the same time by `updated_range`
+const UPDATED_CONCURRENCY: usize = 4;
+// episode pages requested at the same time when fetching all of them
+const EPISODE_PAGES_CONCURRENCY: usize = 4;
+// start of the `404` error message for queries without results
+const NO_RESULTS_MESSAGE: &str = "No results for your query";
 
 /// TheTVDB API async client.
 ///
@@ -29,29 +63,324 @@ const TOKEN_EXP_LIMIT: i64 = 60;
 /// to deserialize API responses, each client method `<method_name>` has an
 /// alternative named `<method_name>_into` which can return data deserialized
 /// into your types.
-#[derive(Debug)]
+///
+/// Cloning a client is cheap: clones share the authentication token, the
+/// cached languages and genres and the HTTP connection pool, so they can be
+/// moved into separate tasks. The language is not shared; changing it with
+/// [`set_language`] only affects that clone.
+///
+/// # Examples
+/// ```no_run
+/// # use thetvdb::{Client, error::Result};
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// #
+/// let client = Client::new("KEY").await?;
+///
+/// let mut korean_client = client.clone();
+/// korean_client.set_language_abbr("ko");
+///
+/// let (series, series_ko) = tokio::join!(
+///     tokio::spawn(async move { client.series(318408).await }),
+///     tokio::spawn(async move { korean_client.series(318408).await }),
+/// );
+/// # Ok(()) }
+/// ```
+///
+/// [`set_language`]: #method.set_language
+#[derive(Clone, Debug)]
 pub struct Client {
     base_url: Url,
     api_key: String,
-    token: Mutex<Option<TokenData>>,
+    token: Arc<Mutex<Option<TokenData>>>,
     http_client: HttpClient,
     lang_abbr: String,
+    languages: Arc<Mutex<Option<Vec<Language>>>>,
+    genres: Arc<std::sync::Mutex<Vec<Genre>>>,
+    default_headers: HeaderMap,
+    accept: HeaderValue,
+    request_semaphore: Option<Arc<Semaphore>>,
+    verify_ids: bool,
+    always_send_language: bool,
+    retry: Option<RetryConfig>,
+    on_retry: Option<RetryHook>,
+    manage_token: bool,
+    timeout: Option<std::time::Duration>,
+    user: Option<UserAuth>,
 }
 
 impl Client {
     /// Create a new client and authenticate using the given api key.
     ///
     /// # Errors
-    /// Will fail if the api key is not valid.
+    /// Will fail if the api key is empty or not valid.
     pub async fn new<S>(api_key: S) -> Result<Self>
     where
         S: Into<String>,
     {
-        let client = Self::create(api_key);
+        Self::builder(api_key).build().await
+    }
+
+    /// Create a new client and authenticate a user account using the given
+    /// api key, username and user key.
+    ///
+    /// Tokens obtained this way also authorize the requests to the `/user`
+    /// endpoints. The user key can be found on the account page of TheTVDB
+    /// website.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::new_user("KEY", "USERNAME", "USER_KEY").await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will fail if the api key is empty or if the credentials are not valid.
+    pub async fn new_user<S, U, K>(api_key: S, username: U, userkey: K) -> Result<Self>
+    where
+        S: Into<String>,
+        U: Into<String>,
+        K: Into<String>,
+    {
+        Self::builder(api_key).user(username, userkey).build().await
+    }
+
+    /// Create a new client that sends its requests using the given
+    /// [`reqwest::Client`] and authenticate using the given api key.
+    ///
+    /// Allows sharing a connection pool and HTTP settings (proxy, root
+    /// certificates, timeouts, etc.) with the rest of an application.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// use std::time::Duration;
+    ///
+    /// let http_client = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .build()?;
+    ///
+    /// let client = Client::with_http_client("KEY", http_client.clone()).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will fail if the api key is empty or not valid.
+    ///
+    /// [`reqwest::Client`]: https://docs.rs/reqwest/latest/reqwest/struct.Client.html
+    pub async fn with_http_client<S>(api_key: S, http_client: HttpClient) -> Result<Self>
+    where
+        S: Into<String>,
+    {
+        Self::builder(api_key)
+            .http_client(http_client)
+            .build()
+            .await
+    }
+
+    /// Create a new client without authenticating.
+    ///
+    /// Unlike [`new`], no login request is sent until the client sends its
+    /// first request, so creating the client cannot fail, e.g. when offline.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::new_lazy("KEY");
+    ///
+    /// // logs in before requesting the series
+    /// let series = client.series(318408).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`new`]: #method.new
+    pub fn new_lazy<S>(api_key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::create(api_key)
+    }
+
+    /// Create a [`ClientBuilder`] to configure a new client.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::builder("KEY")
+    ///     .preload_languages(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`ClientBuilder`]: struct.ClientBuilder.html
+    pub fn builder<S>(api_key: S) -> ClientBuilder
+    where
+        S: Into<String>,
+    {
+        ClientBuilder::new(api_key.into())
+    }
+
+    /// Create a new client using a previously obtained authentication token.
+    ///
+    /// No login request is sent. The token is decoded to find out when it
+    /// expires and, like with [`new`], a new one will be requested using
+    /// the api key when that time is near. If the token has already expired,
+    /// the client logs in again before sending its first request.
+    ///
+    /// Useful in short-lived processes that share the token with each other.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # fn stored_token() -> String { String::new() }
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::with_token("KEY", stored_token())?;
+    ///
+    /// let series = client.series(318408).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will fail if the token cannot be decoded.
+    ///
+    /// [`new`]: #method.new
+    pub fn with_token<S, T>(api_key: S, token: T) -> Result<Self>
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Ok(Client {
+            token: Arc::new(Mutex::new(Some(Self::decode_token(token.into())?))),
+            ..Self::create(api_key)
+        })
+    }
+
+    /// Get the authentication token currently used by the client, along with
+    /// the times when it was issued and when it expires.
+    ///
+    /// The snapshot can be stored and used later to create a new client with
+    /// [`with_token`].
+    ///
+    /// Returns `None` if the client has no token.
+    ///
+    /// **Note**: the raw token grants access to the API on behalf of your api
+    /// key, so make sure it is stored securely.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::new("KEY").await?;
+    ///
+    /// let snapshot = client.export_token().await.unwrap();
+    ///
+    /// let other_client = Client::with_token("KEY", snapshot.token)?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`with_token`]: #method.with_token
+    pub async fn export_token(&self) -> Option<TokenSnapshot> {
+        self.token.lock().await.as_ref().map(|t| TokenSnapshot {
+            token: t.token.clone(),
+            created: t.created,
+            exp: t.exp,
+        })
+    }
+
+    /// Get the authentication token currently used by the client.
+    ///
+    /// Returns `None` if the client has no token. See [`export_token`] to
+    /// also get the times when it was issued and when it expires.
+    ///
+    /// [`export_token`]: #method.export_token
+    pub async fn token(&self) -> Option<String> {
+        self.token.lock().await.as_ref().map(|t| t.token.clone())
+    }
+
+    /// Get the date and time when the client's current authentication token
+    /// expires.
+    ///
+    /// Returns `None` if the client has no token.
+    pub async fn token_expiry(&self) -> Option<DateTime<Utc>> {
+        self.token.lock().await.as_ref().map(|t| t.exp)
+    }
 
-        client.login_set_token().await?;
+    /// Log in and replace the client's token with a new one.
+    ///
+    /// The client does this automatically when its token is about to expire,
+    /// so calling this method is only needed to refresh the token ahead of
+    /// time (see [`time_until_refresh`]).
+    ///
+    /// # Errors
+    /// Will fail if the api key is not valid.
+    ///
+    /// [`time_until_refresh`]: #method.time_until_refresh
+    pub async fn authenticate(&self) -> Result<()> {
+        self.login_set_token().await
+    }
 
-        Ok(client)
+    /// Get how long until the client's token needs to be refreshed.
+    ///
+    /// The client refreshes its token before sending a request if it expires
+    /// in less than a minute, using the API's `/refresh_token` endpoint, or
+    /// logs in again if the token cannot be refreshed. This method returns
+    /// the time left until then, or zero if the token needs to be refreshed
+    /// already.
+    ///
+    /// Returns `None` if the client has no token.
+    ///
+    /// Can be used to refresh the token with [`authenticate`] in the background,
+    /// before any request needs to wait for it.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::new("KEY").await?;
+    ///
+    /// if let Some(duration) = client.time_until_refresh().await {
+    ///     tokio::time::sleep(duration).await;
+    ///
+    ///     client.authenticate().await?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`authenticate`]: #method.authenticate
+    pub async fn time_until_refresh(&self) -> Option<std::time::Duration> {
+        self.token.lock().await.as_ref().map(|t| {
+            (t.exp - Duration::seconds(TOKEN_EXP_LIMIT) - Utc::now())
+                .to_std()
+                .unwrap_or_default()
+        })
     }
 
     /// Set the language for the client.
@@ -126,6 +455,33 @@ impl Client {
         self.lang_abbr = abbr.into();
     }
 
+    /// Returns the abbreviation of the language used by the client.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let mut client = Client::new("KEY").await?;
+    /// #
+    /// assert_eq!(client.language_abbr(), "en");
+    ///
+    /// client.set_language_abbr("ko");
+    ///
+    /// assert_eq!(client.language_abbr(), "ko");
+    /// # Ok(()) }
+    /// ```
+    pub fn language_abbr(&self) -> &str {
+        &self.lang_abbr
+    }
+
+    /// Returns `true` if the client uses the default language, **English**.
+    pub fn is_default_language(&self) -> bool {
+        self.lang_abbr == DEFAULT_LANG_ABBR
+    }
+
     /// Search for series providing either a (partial) name, IMDb id, slug or
     /// Zap2it id.
     ///
@@ -150,6 +506,9 @@ impl Client {
     /// );
     /// # Ok(()) }
     /// ```
+    ///
+    /// Returns an empty list if nothing matches, even though the API responds
+    /// with `404 Not Found` in that case.
     pub async fn search<S>(&self, param: SearchBy<S>) -> Result<Vec<SearchSeries>>
     where
         S: AsRef<str>,
@@ -166,27 +525,32 @@ impl Client {
         S: AsRef<str>,
         T: DeserializeOwned,
     {
-        let res = self
+        let req = self
             .prep_lang_req(Method::GET, self.search_url())
             .await?
-            .query(&param.query_param())
-            .send()
-            .await?;
+            .query(&param.query_param());
 
-        api_errors(&res)?;
+        let res = self.send(req).await?;
+
+        // the API responds with 404 when nothing matches
+        if res.status() == StatusCode::NOT_FOUND {
+            return Ok(Vec::new());
+        }
+
+        let res = api_errors(res).await?;
 
         Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
     }
 
-    /// Get a series by its id.
+    /// Get a page of search results.
     ///
-    /// Sends a `GET` request to the `/series/{id}` API endpoint.
+    /// Same as [`search`], but also sends the `page` parameter and keeps the
+    /// page links in case the API returns them. At the moment the API returns
+    /// all results at once, without page links.
     ///
-    /// References to `SearchSeries`, `Series`, `SeriesUpdate` or any type that
-    /// impls `Into<SeriesID>` can also be used for ids.
+    /// Returns an empty response without page links if nothing matches.
     ///
     /// # Examples
-    /// Use a literal id:
     /// ```no_run
     /// # use thetvdb::{Client, error::Result};
     /// #
@@ -195,67 +559,70 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// let series = client.series(318408).await?;
-    ///
-    /// assert_eq!(
-    ///     series.series_name,
-    ///     Some("Planet Earth II".to_string())
-    /// );
-    /// # Ok(()) }
-    /// ```
+    /// use thetvdb::params::SearchBy;
     ///
-    /// Use a search result:
-    /// ```no_run
-    /// # use thetvdb::{Client, error::Result, params::SearchBy};
-    /// #
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<()> {
-    /// #
-    /// # let client = Client::new("KEY").await?;
-    /// #
-    /// let results = client.search(SearchBy::IMDbID("tt5491994")).await?;
+    /// let response = client.search_page(SearchBy::Name("Planet Earth"), 1).await?;
     ///
-    /// let series = client.series(&results[0]).await?;
+    /// for series in &response.data {
+    ///     println!("{:?}", series.series_name);
+    /// }
     ///
-    /// assert_eq!(
-    ///     series.series_name,
-    ///     Some("Planet Earth II".to_string())
-    /// );
+    /// if let Some(next) = response.next_page() {
+    ///     println!("next page: {}", next);
+    /// }
     /// # Ok(()) }
     /// ```
     ///
-    /// # Errors
-    /// Will return an error if the series is not found.
-    pub async fn series<I>(&self, id: I) -> Result<Series>
+    /// [`search`]: #method.search
+    pub async fn search_page<S>(&self, param: SearchBy<S>, page: u16) -> Result<SearchResponse>
     where
-        I: Into<SeriesID>,
+        S: AsRef<str>,
     {
-        self.series_into(id).await
+        self.search_page_into(param, page).await
     }
 
-    /// Same as [`series`], but allows deserializing the response series data
-    /// into a provided type.
+    /// Same as [`search_page`], but allows deserializing the response search
+    /// series data into a provided type.
     ///
-    /// [`series`]: #method.series
-    pub async fn series_into<T, I>(&self, id: I) -> Result<T>
+    /// [`search_page`]: #method.search_page
+    pub async fn search_page_into<T, S>(
+        &self,
+        param: SearchBy<S>,
+        page: u16,
+    ) -> Result<SearchResponse<T>>
     where
-        I: Into<SeriesID>,
+        S: AsRef<str>,
         T: DeserializeOwned,
     {
-        let res = self
-            .prep_lang_req(Method::GET, self.series_url(id.into()))
+        let req = self
+            .prep_lang_req(Method::GET, self.search_url())
             .await?
-            .send()
-            .await?;
+            .query(&param.query_param())
+            .query(&[("page", page)]);
 
-        api_errors(&res)?;
+        let res = self.send(req).await?;
 
-        Ok(res.json::<ResponseData<T>>().await?.data)
+        // the API responds with 404 when nothing matches
+        if res.status() == StatusCode::NOT_FOUND {
+            return Ok(SearchResponse {
+                data: Vec::new(),
+                links: None,
+            });
+        }
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json().await?)
     }
 
-    /// Get the last modified time of a series.
+    /// Search for series and get the full data of the results rated at most
+    /// `max_rating`.
     ///
-    /// Sends a `HEAD` request to the `/series/{id}` API endpoint.
+    /// Search results don't contain ratings, so this sends a [`search`]
+    /// request followed by a [`series`] request for each result (see
+    /// [`series_many`]), which can take a while for large result sets.
+    ///
+    /// Series without a known [`ContentRating`] are left out.
     ///
     /// # Examples
     /// ```no_run
@@ -266,40 +633,46 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// use chrono::Utc;
+    /// use thetvdb::{params::SearchBy, response::ContentRating};
     ///
-    /// let last_modified = client.series_last_modified(318408).await?;
+    /// let results = client
+    ///     .search_filtered(SearchBy::Name("planet"), ContentRating::TVPG)
+    ///     .await?;
     ///
-    /// assert!(last_modified < Utc::now());
+    /// for series in results {
+    ///     println!("{:?}: {:?}", series.series_name, series.rating);
+    /// }
     /// # Ok(()) }
     /// ```
     ///
     /// # Errors
-    /// Will return an error if the series is not found.
-    pub async fn series_last_modified<I>(&self, id: I) -> Result<DateTime<Utc>>
+    /// Will return an error if any of the series requests fails.
+    ///
+    /// [`search`]: #method.search
+    /// [`series`]: #method.series
+    /// [`series_many`]: #method.series_many
+    /// [`ContentRating`]: ../response/enum.ContentRating.html
+    pub async fn search_filtered<S>(
+        &self,
+        param: SearchBy<S>,
+        max_rating: ContentRating,
+    ) -> Result<Vec<Series>>
     where
-        I: Into<SeriesID>,
+        S: AsRef<str>,
     {
-        let res = self
-            .prep_req(Method::HEAD, self.series_url(id.into()))
-            .await?
-            .send()
-            .await?;
+        let results = self.search(param).await?;
 
-        api_errors(&res)?;
+        let mut series = self.series_many(&results).await?;
 
-        let lm_header = res
-            .headers()
-            .get("Last-Modified")
-            .ok_or(Error::MissingLastModified)
-            .map(HeaderValue::to_str)??;
+        series.retain(|s| s.content_rating().filter(|r| *r <= max_rating).is_some());
 
-        Ok(DateTime::parse_from_rfc2822(lm_header)?.into())
+        Ok(series)
     }
 
-    /// Get a list of actors playing in a given series.
+    /// Get the full data of the series with the given Zap2it id.
     ///
-    /// Sends a `GET` request to the `/series/{id}/actors` API endpoint.
+    /// Sends a [`search`] request by Zap2it id followed by a [`series`]
+    /// request for the first result.
     ///
     /// # Examples
     /// ```no_run
@@ -310,46 +683,29 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// let actors = client.series_actors(318408).await?;
+    /// let series = client.series_by_zap2it("EP02545640").await?;
     ///
-    /// assert_eq!(&actors[0].name, "David Attenborough");
+    /// assert_eq!(
+    ///     series.unwrap().series_name,
+    ///     Some("Planet Earth II".to_string())
+    /// );
     /// # Ok(()) }
     /// ```
     ///
     /// # Errors
-    /// Will return an error if the series is not found.
-    pub async fn series_actors<I>(&self, id: I) -> Result<Vec<Actor>>
-    where
-        I: Into<SeriesID>,
-    {
-        self.series_actors_into(id).await
-    }
-
-    /// Same as [`series_actors`], but allows deserializing the response actor
-    /// data into a provided type.
+    /// Will return an error if the [`series`] request fails. Returns `None`
+    /// if no series has the given id.
     ///
-    /// [`series_actors`]: #method.series_actors
-    pub async fn series_actors_into<T, I>(&self, id: I) -> Result<Vec<T>>
-    where
-        I: Into<SeriesID>,
-        T: DeserializeOwned,
-    {
-        let res = self
-            .prep_req(Method::GET, self.series_actors_url(id.into()))
-            .await?
-            .send()
-            .await?;
-
-        api_errors(&res)?;
-
-        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+    /// [`search`]: #method.search
+    /// [`series`]: #method.series
+    pub async fn series_by_zap2it(&self, id: &str) -> Result<Option<Series>> {
+        self.first_search_result(SearchBy::Zap2itID(id)).await
     }
 
-    /// Get a page of a series' episodes.
+    /// Get the full data of the series with the given IMDb id.
     ///
-    /// Sends a `GET` request to the `/series/{id}/episodes` API endpoint.
-    ///
-    /// A page contains 100 episodes at most.
+    /// Sends a [`search`] request by IMDb id followed by a [`series`] request
+    /// for the first result.
     ///
     /// # Examples
     /// ```no_run
@@ -360,64 +716,34 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// use thetvdb::params::EpisodeParams;
-    ///
-    /// // get the first page
-    /// let episode_params = EpisodeParams::new(121361);
-    /// let episode_page = client.series_episodes(&episode_params).await?;
-    ///
-    /// // get the next page
-    /// let next_page_params = episode_page.next_page_params().unwrap();
-    /// let next_page = client.series_episodes(&next_page_params).await?;
-    ///
-    /// // get the previous page
-    /// let prev_page_params = next_page.prev_page_params().unwrap();
-    /// let prev_page = client.series_episodes(&prev_page_params).await?;
+    /// let series = client.series_by_imdb("tt5491994").await?;
     ///
-    /// // get a custom page
-    /// let custom_page_params = EpisodeParams::with_page(121361, 2);
-    /// let custom_page = client.series_episodes(&custom_page_params).await?;
-    ///
-    /// // print an episode
-    /// println!("{:#?}", episode_page.episodes[0]);
+    /// assert_eq!(
+    ///     series.unwrap().series_name,
+    ///     Some("Planet Earth II".to_string())
+    /// );
     /// # Ok(()) }
     /// ```
+    ///
     /// # Errors
-    /// Will return an error if the series is not found.
-    pub async fn series_episodes(&self, params: &EpisodeParams) -> Result<EpisodePage> {
-        self.series_episodes_into(params).await
-    }
-
-    /// Same as [`series_episodes`], but allows deserializing the response
-    /// episode data into a provided type.
+    /// Will return an error if the [`series`] request fails. Returns `None`
+    /// if no series has the given id.
     ///
-    /// [`series_episodes`]: #method.series_episodes
-    pub async fn series_episodes_into<T>(&self, params: &EpisodeParams) -> Result<EpisodePage<T>>
-    where
-        T: DeserializeOwned,
-    {
-        let res = self
-            .prep_req(Method::GET, self.series_episodes_url(params.series_id))
-            .await?
-            .query(&[("page", params.page)])
-            .send()
-            .await?;
-
-        api_errors(&res)?;
-
-        let mut page: EpisodePage<T> = res.json().await?;
-        page.series_id = params.series_id;
-
-        Ok(page)
+    /// [`search`]: #method.search
+    /// [`series`]: #method.series
+    pub async fn series_by_imdb(&self, imdb_id: &str) -> Result<Option<Series>> {
+        self.first_search_result(SearchBy::IMDbID(imdb_id)).await
     }
 
-    /// Get a page of a series' episodes queried with the given params.
+    /// Get a series by its id.
     ///
-    /// Sends a `GET` request to the `/series/{id}/episodes/query` API endpoint.
+    /// Sends a `GET` request to the `/series/{id}` API endpoint.
     ///
-    /// Check [`series_episodes`] for pagination examples.
+    /// References to `SearchSeries`, `Series`, `SeriesUpdate` or any type that
+    /// impls `Into<SeriesID>` can also be used for ids.
     ///
     /// # Examples
+    /// Use a literal id:
     /// ```no_run
     /// # use thetvdb::{Client, error::Result};
     /// #
@@ -426,71 +752,69 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// use thetvdb::params::EpisodeQueryParams;
+    /// let series = client.series(318408).await?;
     ///
-    /// let query = EpisodeQueryParams::new(318408)
-    ///     .absolute_number(1)
-    ///     .aired_season(1)
-    ///     .aired_episode(1)
-    ///     .dvd_season(1)
-    ///     .dvd_episode(1);
+    /// assert_eq!(
+    ///     series.series_name,
+    ///     Some("Planet Earth II".to_string())
+    /// );
+    /// # Ok(()) }
+    /// ```
     ///
-    /// let episode_page = client.series_episodes_query(&query).await?;
+    /// Use a search result:
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result, params::SearchBy};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let results = client.search(SearchBy::IMDbID("tt5491994")).await?;
+    ///
+    /// let series = client.series(&results[0]).await?;
     ///
     /// assert_eq!(
-    ///     episode_page.episodes[0].episode_name,
-    ///     Some("Islands".to_string())
+    ///     series.series_name,
+    ///     Some("Planet Earth II".to_string())
     /// );
     /// # Ok(()) }
     /// ```
     ///
     /// # Errors
-    /// Will return an error if the series is not found or the data set is
-    /// empty.
-    ///
-    /// [`series_episodes`]: #method.series_episodes
-    pub async fn series_episodes_query(
-        &self,
-        query_params: &EpisodeQueryParams,
-    ) -> Result<EpisodeQueryPage> {
-        self.series_episodes_query_into(query_params).await
+    /// Will return an error if the series is not found.
+    pub async fn series<I>(&self, id: I) -> Result<Series>
+    where
+        I: Into<SeriesID>,
+    {
+        self.series_into(id).await
     }
 
-    /// Same as [`series_episodes_query`], but allows deserializing the response
-    /// episode data into a provided type.
+    /// Same as [`series`], but allows deserializing the response series data
+    /// into a provided type.
     ///
-    /// [`series_episodes_query`]: #method.series_episodes_query
-    pub async fn series_episodes_query_into<T>(
-        &self,
-        query_params: &EpisodeQueryParams,
-    ) -> Result<EpisodeQueryPage<T>>
+    /// [`series`]: #method.series
+    pub async fn series_into<T, I>(&self, id: I) -> Result<T>
     where
+        I: Into<SeriesID>,
         T: DeserializeOwned,
     {
-        let res = self
-            .prep_lang_req(
-                Method::GET,
-                self.series_episodes_query_url(query_params.params.series_id),
-            )
-            .await?
-            .query(&[("page", query_params.params.page)])
-            .query(&query_params.query)
-            .send()
-            .await?;
+        let id = id.into();
 
-        api_errors(&res)?;
+        let req = self.prep_lang_req(Method::GET, self.series_url(id)).await?;
 
-        let mut page: EpisodeQueryPage<T> = res.json().await?;
-        page.series_id = query_params.params.series_id;
-        page.query = query_params.query.clone();
+        let res = self.send(req).await?;
 
-        Ok(page)
+        let res = api_errors(res).await?;
+
+        self.data_with_id(res, id.0.into()).await
     }
 
-    /// Get the summary of a series' episodes.
+    /// Get a series's data both as [`Series`] and as raw JSON.
     ///
-    /// Sends a `GET` request to the `/series/{id}/episodes/summary` API
-    /// endpoint.
+    /// The response is only parsed once, so the raw JSON always matches the
+    /// returned `Series`. Useful for storing the raw data while also using the
+    /// typed value.
     ///
     /// # Examples
     /// ```no_run
@@ -501,46 +825,33 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// let summary = client.series_episodes_summary(318408).await?;
+    /// let (series, raw) = client.series_with_raw(318408).await?;
     ///
-    /// assert_eq!(summary.aired_episodes, 18);
+    /// assert_eq!(raw["seriesName"], "Planet Earth II");
     /// # Ok(()) }
     /// ```
     ///
     /// # Errors
-    /// Will return an error if the series is not found.
-    pub async fn series_episodes_summary<I>(&self, id: I) -> Result<EpisodeSummary>
-    where
-        I: Into<SeriesID>,
-    {
-        self.series_episodes_summary_into(id).await
-    }
-
-    /// Same as [`series_episodes_summary`], but allows deserializing the
-    /// response episode summary data into a provided type.
+    /// Will return an error if the series is not found or if the raw data is
+    /// not a valid `Series`.
     ///
-    /// [`series_episodes_summary`]: #method.series_episodes_summary
-    pub async fn series_episodes_summary_into<T, I>(&self, id: I) -> Result<T>
+    /// [`Series`]: ../response/struct.Series.html
+    pub async fn series_with_raw<I>(&self, id: I) -> Result<(Series, serde_json::Value)>
     where
         I: Into<SeriesID>,
-        T: DeserializeOwned,
     {
-        let res = self
-            .prep_req(Method::GET, self.series_episodes_summary_url(id.into()))
-            .await?
-            .send()
-            .await?;
+        let raw: serde_json::Value = self.series_into(id).await?;
 
-        api_errors(&res)?;
+        let series = Series::deserialize(&raw)?;
 
-        Ok(res.json::<ResponseData<T>>().await?.data)
+        Ok((series, raw))
     }
 
-    /// Get only selected fields of a series.
-    ///
-    /// Sends a `GET` request to the `/series/{id}/filter` API endpoint.
+    /// Get a series along with the actors playing in it.
     ///
-    /// This can be more efficient than getting all the fields.
+    /// TheTVDB API does not support embedding related data in the series
+    /// response, so this method sends the [`series`] and [`series_actors`]
+    /// requests concurrently and combines their results.
     ///
     /// # Examples
     /// ```no_run
@@ -551,62 +862,36 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// use thetvdb::params::SeriesFilterKeys;
-    ///
-    /// let keys = SeriesFilterKeys::new().series_name();
-    ///
-    /// let filtered_series = client.series_filter(318408, &keys).await?;
+    /// let detail = client.series_detail(318408).await?;
     ///
     /// assert_eq!(
-    ///     filtered_series.series_name,
+    ///     detail.series.series_name,
     ///     Some("Planet Earth II".to_string())
     /// );
-    ///
-    /// assert_eq!(filtered_series.id, None);
+    /// assert_eq!(&detail.actors[0].name, "David Attenborough");
     /// # Ok(()) }
     /// ```
     ///
     /// # Errors
     /// Will return an error if the series is not found.
-    pub async fn series_filter<I>(
-        &self,
-        id: I,
-        filter_keys: &SeriesFilterKeys,
-    ) -> Result<FilteredSeries>
-    where
-        I: Into<SeriesID>,
-    {
-        self.series_filter_into(id, filter_keys).await
-    }
-
-    /// Same as [`series_filter`], but allows deserializing the response series
-    /// data into a provided type.
     ///
-    /// [`series_filter`]: #method.series_filter
-    pub async fn series_filter_into<T, I>(&self, id: I, filter_keys: &SeriesFilterKeys) -> Result<T>
+    /// [`series`]: #method.series
+    /// [`series_actors`]: #method.series_actors
+    pub async fn series_detail<I>(&self, id: I) -> Result<SeriesDetail>
     where
         I: Into<SeriesID>,
-        T: DeserializeOwned,
     {
-        if filter_keys.is_empty() {
-            return Err(Error::MissingSeriesFilterKeys);
-        }
-
-        let res = self
-            .prep_lang_req(Method::GET, self.series_filter_url(id.into()))
-            .await?
-            .query(&[("keys", &filter_keys.keys_query)])
-            .send()
-            .await?;
+        let id = id.into();
 
-        api_errors(&res)?;
+        let (series, actors) = futures::try_join!(self.series(id), self.series_actors(id))?;
 
-        Ok(res.json::<ResponseData<T>>().await?.data)
+        Ok(SeriesDetail { series, actors })
     }
 
-    /// Get a summary of a series' images.
+    /// Get many series by their ids.
     ///
-    /// Sends a `GET` request to the `/series/{id}/images` API endpoint.
+    /// Sends a [`series`] request for each id, a few of them at a time, and
+    /// returns the series in the same order as the ids.
     ///
     /// # Examples
     /// ```no_run
@@ -617,118 +902,127 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// let image_summary = client.series_images(318408).await?;
+    /// let series = client.series_many(vec![318408, 121361]).await?;
     ///
-    /// assert_eq!(image_summary.poster, Some(8));
+    /// assert_eq!(series.len(), 2);
     /// # Ok(()) }
     /// ```
     ///
     /// # Errors
-    /// Will return an error if the series is not found.
-    pub async fn series_images<I>(&self, id: I) -> Result<SeriesImages>
-    where
-        I: Into<SeriesID>,
-    {
-        self.series_images_into(id).await
-    }
-
-    /// Same as [`series_images`], but allows deserializing the response image
-    /// summary data into a provided type.
+    /// Will return an error if any of the series is not found.
     ///
-    /// [`series_images`]: #method.series_images
-    pub async fn series_images_into<T, I>(&self, id: I) -> Result<T>
+    /// [`series`]: #method.series
+    pub async fn series_many<T>(&self, ids: T) -> Result<Vec<Series>>
     where
-        I: Into<SeriesID>,
-        T: DeserializeOwned,
+        T: IntoIterator,
+        T::Item: Into<SeriesID>,
     {
-        let res = self
-            .prep_lang_req(Method::GET, self.series_images_url(id.into()))
-            .await?
-            .send()
-            .await?;
-
-        api_errors(&res)?;
-
-        Ok(res.json::<ResponseData<T>>().await?.data)
+        stream::iter(ids.into_iter().map(|id| self.series(id)))
+            .buffered(SERIES_MANY_CONCURRENCY)
+            .try_collect()
+            .await
     }
 
-    /// Get a series' images based on query parameters.
-    ///
-    /// Sends a `GET` request to the `/series/{id}/images/query` API endpoint.
+    /// Get many series by their ids, keeping the series that were fetched
+    /// even if some requests fail.
     ///
-    /// Each series may have different available image types.
-    /// To find out which key types, resolutions and subkeys are available for
-    /// a series use the [`series_images_query_params`] method.
+    /// Works like [`series_many`], but returns the fetched series and the
+    /// ids that failed together with their errors, both in the same order as
+    /// the ids. The errors can be combined with [`Error::Multiple`].
     ///
     /// # Examples
     /// ```no_run
-    /// # use thetvdb::{Client, error::Result};
+    /// # use thetvdb::{Client, error::{Error, Result}};
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// use thetvdb::params::ImageQueryParams;
+    /// let (series, failed) = client.series_many_partial(vec![318408, 121361]).await;
     ///
-    /// let params = ImageQueryParams::with_key_type("poster");
+    /// if !failed.is_empty() {
+    ///     let errors = failed.into_iter().map(|(_, e)| e).collect();
     ///
-    /// let images = client.
-    ///     series_images_query(318408, &params)
-    ///     .await?;
-    ///
-    /// assert_eq!(images.len(), 8);
-    ///
-    /// // print an image's URL
-    /// println!("{}", images[0].file_name_url()?);
+    ///     eprintln!("{}", Error::Multiple(errors));
+    /// }
     /// # Ok(()) }
     /// ```
     ///
-    /// # Errors
-    /// Will return an error if the series is not found or the data set is
-    /// empty.
-    ///
-    /// [`series_images_query_params`]: #method.series_images_query_params
-    pub async fn series_images_query<I>(
-        &self,
-        id: I,
-        params: &ImageQueryParams,
-    ) -> Result<Vec<Image>>
+    /// [`series_many`]: #method.series_many
+    /// [`Error::Multiple`]: ../error/enum.Error.html#variant.Multiple
+    pub async fn series_many_partial<T>(&self, ids: T) -> (Vec<Series>, Vec<(SeriesID, Error)>)
     where
-        I: Into<SeriesID>,
+        T: IntoIterator,
+        T::Item: Into<SeriesID>,
     {
-        self.series_images_query_into(id, params).await
+        let results: Vec<(SeriesID, Result<Series>)> = stream::iter(ids.into_iter().map(|id| {
+            let id = id.into();
+
+            async move { (id, self.series(id).await) }
+        }))
+        .buffered(SERIES_MANY_CONCURRENCY)
+        .collect()
+        .await;
+
+        let mut series = Vec::new();
+        let mut failed = Vec::new();
+
+        for (id, result) in results {
+            match result {
+                Ok(s) => series.push(s),
+                Err(e) => failed.push((id, e)),
+            }
+        }
+
+        (series, failed)
     }
 
-    /// Same as [`series_images_query`], but allows deserializing the response
-    /// image data into a provided type.
+    /// Get the last modified time of a series.
     ///
-    /// [`series_images_query`]: #method.series_images_query
-    pub async fn series_images_query_into<T, I>(
-        &self,
-        id: I,
-        params: &ImageQueryParams,
-    ) -> Result<Vec<T>>
+    /// Sends a `HEAD` request to the `/series/{id}` API endpoint.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use chrono::Utc;
+    ///
+    /// let last_modified = client.series_last_modified(318408).await?;
+    ///
+    /// assert!(last_modified < Utc::now());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    pub async fn series_last_modified<I>(&self, id: I) -> Result<DateTime<Utc>>
     where
         I: Into<SeriesID>,
-        T: DeserializeOwned,
     {
-        let res = self
-            .prep_lang_req(Method::GET, self.series_images_query_url(id.into()))
-            .await?
-            .query(&params)
-            .send()
+        let req = self
+            .prep_req(Method::HEAD, self.series_url(id.into()))
             .await?;
 
-        api_errors(&res)?;
+        let res = self.send(req).await?;
 
-        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+        let res = api_errors(res).await?;
+
+        last_modified(&res)
     }
 
-    /// Get a series' available image key types, resolutions and subkeys.
+    /// Get a list of actors playing in a given series.
     ///
-    /// Sends a `GET` request to the `/series/{id}/images/query/params` API
-    /// endpoint.
+    /// Sends a `GET` request to the `/series/{id}/actors` API endpoint.
+    ///
+    /// The client's language is not sent, so roles are returned in the API's
+    /// default language. Use [`series_actors_in_language`] to request them
+    /// in a given language.
     ///
     /// # Examples
     /// ```no_run
@@ -739,100 +1033,87 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// let image_keys = client
-    ///     .series_images_query_params(318408)
-    ///     .await?;
+    /// let actors = client.series_actors(318408).await?;
     ///
-    /// // print resolutions available for the first image key type
-    /// println!("{:#?}", image_keys[0].resolution);
+    /// assert_eq!(&actors[0].name, "David Attenborough");
     /// # Ok(()) }
     /// ```
     ///
     /// # Errors
     /// Will return an error if the series is not found.
-    pub async fn series_images_query_params<I>(&self, id: I) -> Result<Vec<ImageQueryKey>>
-    where
-        I: Into<SeriesID>,
-    {
-        self.series_images_query_params_into(id).await
-    }
-
-    /// Same as [`series_images_query_params`], but allows deserializing the
-    /// response image key data into a provided type.
     ///
-    /// [`series_images_query_params`]: #method.series_images_query_params
-    pub async fn series_images_query_params_into<T, I>(&self, id: I) -> Result<Vec<T>>
+    /// [`series_actors_in_language`]: #method.series_actors_in_language
+    pub async fn series_actors<I>(&self, id: I) -> Result<Vec<Actor>>
     where
         I: Into<SeriesID>,
-        T: DeserializeOwned,
     {
-        let res = self
-            .prep_lang_req(Method::GET, self.series_images_query_params_url(id.into()))
-            .await?
-            .send()
-            .await?;
-
-        api_errors(&res)?;
-
-        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+        self.series_actors_into(id).await
     }
 
-    /// Get an episode by its id.
+    /// Get a list of actors playing in a given series, with their roles in
+    /// the given language if available.
     ///
-    /// Sends a `GET` request to the `/episodes/{id}` API endpoint.
+    /// Same as [`series_actors`], but the language abbreviation is sent as
+    /// the `Accept-Language` header. Roles without a translation are
+    /// returned as the API provides them.
     ///
     /// # Examples
     /// ```no_run
-    /// # use thetvdb::{Client, error::Result, response::SeriesID};
+    /// # use thetvdb::{Client, error::Result};
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<()> {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// let episode = client.episode(5812389).await?;
-    ///
-    /// assert_eq!(episode.episode_name, Some("Islands".to_string()));
-    /// assert_eq!(episode.series_id, SeriesID(318408));
+    /// let actors = client.series_actors_in_language(318408, "de").await?;
     /// # Ok(()) }
     /// ```
     ///
     /// # Errors
-    /// Will return an error if the episode is not found.
-    pub async fn episode<I>(&self, id: I) -> Result<Episode>
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_actors`]: #method.series_actors
+    pub async fn series_actors_in_language<I>(&self, id: I, lang_abbr: &str) -> Result<Vec<Actor>>
     where
-        I: Into<EpisodeID>,
+        I: Into<SeriesID>,
     {
-        self.episode_into(id).await
+        let req = self
+            .prep_abbr_req(Method::GET, self.series_actors_url(id.into()), lang_abbr)
+            .await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<Vec<Actor>>>().await?.data)
     }
 
-    /// Same as [`episode`], but allows deserializing the response episode data
-    /// into a provided type.
+    /// Same as [`series_actors`], but allows deserializing the response actor
+    /// data into a provided type.
     ///
-    /// [`episode`]: #method.episode
-    pub async fn episode_into<T, I>(&self, id: I) -> Result<T>
+    /// [`series_actors`]: #method.series_actors
+    pub async fn series_actors_into<T, I>(&self, id: I) -> Result<Vec<T>>
     where
-        I: Into<EpisodeID>,
+        I: Into<SeriesID>,
         T: DeserializeOwned,
     {
-        let id = id.into();
-
-        let res = self
-            .prep_lang_req(Method::GET, self.episodes_url(id))
-            .await?
-            .send()
+        let req = self
+            .prep_req(Method::GET, self.series_actors_url(id.into()))
             .await?;
 
-        api_errors(&res)?;
+        let res = self.send(req).await?;
 
-        Ok(res.json::<ResponseData<T>>().await?.data)
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
     }
 
-    /// Get a list of all the available languages.
+    /// Get a page of a series' episodes.
     ///
-    /// Sends a `GET` request to the `/languages` API endpoint.
+    /// Sends a `GET` request to the `/series/{id}/episodes` API endpoint.
     ///
-    /// Languages can be used to [`set_language`].
+    /// A page contains 100 episodes at most.
     ///
     /// # Examples
     /// ```no_run
@@ -843,40 +1124,51 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// let languages = client.languages().await?;
+    /// use thetvdb::params::EpisodeParams;
     ///
-    /// // print all the languages
-    /// println!("{:#?}", languages);
+    /// // get the first page
+    /// let episode_params = EpisodeParams::new(121361);
+    /// let episode_page = client.series_episodes(&episode_params).await?;
+    ///
+    /// // get the next page
+    /// let next_page_params = episode_page.next_page_params().unwrap();
+    /// let next_page = client.series_episodes(&next_page_params).await?;
+    ///
+    /// // get the previous page
+    /// let prev_page_params = next_page.prev_page_params().unwrap();
+    /// let prev_page = client.series_episodes(&prev_page_params).await?;
+    ///
+    /// // get a custom page
+    /// let custom_page_params = EpisodeParams::with_page(121361, 2);
+    /// let custom_page = client.series_episodes(&custom_page_params).await?;
+    ///
+    /// // print an episode
+    /// println!("{:#?}", episode_page.episodes[0]);
     /// # Ok(()) }
     /// ```
-    ///
-    /// [`set_language`]: #method.set_language
-    pub async fn languages(&self) -> Result<Vec<Language>> {
-        self.languages_into().await
+    /// # Errors
+    /// Will return an error if the series is not found.
+    pub async fn series_episodes(&self, params: &EpisodeParams) -> Result<EpisodePage> {
+        self.series_episodes_into(params).await
     }
 
-    /// Same as [`languages`], but allows deserializing the response language
-    /// data into a provided type.
+    /// Same as [`series_episodes`], but allows deserializing the response
+    /// episode data into a provided type.
     ///
-    /// [`languages`]: #method.languages
-    pub async fn languages_into<T>(&self) -> Result<Vec<T>>
+    /// [`series_episodes`]: #method.series_episodes
+    pub async fn series_episodes_into<T>(&self, params: &EpisodeParams) -> Result<EpisodePage<T>>
     where
         T: DeserializeOwned,
     {
-        let res = self
-            .prep_req(Method::GET, self.languages_url())
-            .await?
-            .send()
-            .await?;
-
-        api_errors(&res)?;
-
-        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+        self.series_episodes_page(params, None).await
     }
 
-    /// Get a language by its id.
+    /// Get all the episodes of a series.
     ///
-    /// Sends a `GET` request to the `/languages/{id}` API endpoint.
+    /// Requests the first page with [`series_episodes`] to find out which one
+    /// is the last page, then requests the other pages, a few of them at a
+    /// time. The episodes are returned in the same order as the API returns
+    /// them, page after page.
     ///
     /// # Examples
     /// ```no_run
@@ -887,49 +1179,42 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// let japanese = client.language(25).await?;
+    /// let episodes = client.series_episodes_all(121361).await?;
     ///
-    /// assert_eq!(japanese.abbreviation, "ja".to_string());
-    /// assert_eq!(japanese.name, "日本語".to_string());
-    /// assert_eq!(japanese.english_name, "Japanese".to_string());
+    /// println!("{} episodes", episodes.len());
     /// # Ok(()) }
     /// ```
     ///
     /// # Errors
-    /// Will return an error if the language is not found.
-    pub async fn language<I>(&self, id: I) -> Result<Language>
+    /// Will return an error if the series is not found or if any of the pages
+    /// cannot be fetched.
+    ///
+    /// [`series_episodes`]: #method.series_episodes
+    pub async fn series_episodes_all<I>(&self, series_id: I) -> Result<Vec<Episode>>
     where
-        I: Into<LanguageID>,
+        I: Into<SeriesID>,
     {
-        self.language_into(id).await
+        self.series_episodes_all_into(series_id).await
     }
 
-    /// Same as [`language`], but allows deserializing the response language
-    /// data into a provided type.
+    /// Same as [`series_episodes_all`], but allows deserializing the response
+    /// episode data into a provided type.
     ///
-    /// [`language`]: #method.language
-    pub async fn language_into<T, I>(&self, id: I) -> Result<T>
+    /// [`series_episodes_all`]: #method.series_episodes_all
+    pub async fn series_episodes_all_into<T, I>(&self, series_id: I) -> Result<Vec<T>>
     where
-        I: Into<LanguageID>,
         T: DeserializeOwned,
+        I: Into<SeriesID>,
     {
-        let res = self
-            .prep_req(Method::GET, self.language_url(id.into()))
-            .await?
-            .send()
-            .await?;
-
-        api_errors(&res)?;
-
-        Ok(res.json::<ResponseData<T>>().await?.data)
+        self.all_episodes_in(series_id.into(), None).await
     }
 
-    /// Get a list of series updated within a given time period.
-    ///
-    /// Sends a `GET` request to the `/updated/query` API endpoint.
+    /// Get a stream of a series' episodes, starting from the page set in
+    /// `params`.
     ///
-    /// If `to_time` is not set or more than one week after `from_time`, the API
-    /// sets the timespan to one week.
+    /// Pages are requested with [`series_episodes`] only when needed,
+    /// following [`EpisodePage::next_page_params`], and their episodes are
+    /// yielded one at a time.
     ///
     /// # Examples
     /// ```no_run
@@ -940,53 +1225,44 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// use thetvdb::params::UpdatedParams;
-    /// use chrono::DateTime;
-    ///
-    /// let from = DateTime::parse_from_rfc3339("2019-11-10T12:00:00-00:00")?;
-    /// let to = DateTime::parse_from_rfc3339("2019-11-10T12:10:00-00:00")?;
-    ///
-    /// let timespan = UpdatedParams::with_to_time(from, to);
+    /// use futures::TryStreamExt;
+    /// use thetvdb::params::EpisodeParams;
     ///
-    /// let updates = client.updated(&timespan).await?;
+    /// let params = EpisodeParams::new(121361);
     ///
-    /// assert_eq!(updates.len(), 7);
+    /// let mut episodes = Box::pin(client.series_episodes_stream(&params));
     ///
-    /// // results can be used to fetch full series data
-    /// let series = client.series(&updates[0]).await?;
+    /// while let Some(episode) = episodes.try_next().await? {
+    ///     println!("{:?}", episode.episode_name);
+    /// }
     /// # Ok(()) }
     /// ```
     ///
     /// # Errors
-    /// Will return an error if there are no updated series within the
-    /// given timespan.
-    pub async fn updated(&self, params: &UpdatedParams) -> Result<Vec<SeriesUpdate>> {
-        self.updated_into(params).await
-    }
-
-    /// Same as [`updated`], but allows deserializing the response series
-    /// updated data into a provided type.
+    /// The stream yields an error and ends if a page request fails.
     ///
-    /// [`updated`]: #method.updated
-    pub async fn updated_into<T>(&self, params: &UpdatedParams) -> Result<Vec<T>>
-    where
-        T: DeserializeOwned,
-    {
-        let res = self
-            .prep_lang_req(Method::GET, self.updated_url())
-            .await?
-            .query(&params)
-            .send()
-            .await?;
-
-        api_errors(&res)?;
+    /// [`series_episodes`]: #method.series_episodes
+    /// [`EpisodePage::next_page_params`]: ../response/struct.EpisodePage.html#method.next_page_params
+    pub fn series_episodes_stream(
+        &self,
+        params: &EpisodeParams,
+    ) -> impl Stream<Item = Result<Episode>> + '_ {
+        let next = Some(EpisodeParams::with_page(params.series_id, params.page));
 
-        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+        stream::try_unfold(next, move |next| self.episodes_step(next))
+            .map_ok(|episodes| stream::iter(episodes.into_iter().map(Ok)))
+            .try_flatten()
     }
 
-    /// Get a movie by its id.
+    /// Get a stream of a series' episodes, starting from the last page and
+    /// ending with the first.
     ///
-    /// Sends a `GET` request to the `/movies/{id}` API endpoint.
+    /// Episodes on each page are also yielded in reverse order, so the newest
+    /// episodes come first. Pages are requested with [`series_episodes`] only
+    /// when needed.
+    ///
+    /// The page set in `params` is requested first to find out which one is
+    /// the last page.
     ///
     /// # Examples
     /// ```no_run
@@ -997,99 +1273,2950 @@ impl Client {
     /// #
     /// # let client = Client::new("KEY").await?;
     /// #
-    /// let movie = client.movie(190).await?;
+    /// use futures::TryStreamExt;
+    /// use thetvdb::params::EpisodeParams;
     ///
-    /// assert_eq!(movie.runtime, 142);
+    /// let params = EpisodeParams::new(121361);
+    ///
+    /// let newest: Vec<_> = client
+    ///     .series_episodes_reverse_stream(&params)
+    ///     .try_take_while(|e| futures::future::ok(e.aired_season != Some(1)))
+    ///     .try_collect()
+    ///     .await?;
     /// # Ok(()) }
     /// ```
-    pub async fn movie<I>(&self, id: I) -> Result<Movie>
-    where
+    ///
+    /// # Errors
+    /// The stream yields an error and ends if a page request fails.
+    ///
+    /// [`series_episodes`]: #method.series_episodes
+    pub fn series_episodes_reverse_stream(
+        &self,
+        params: &EpisodeParams,
+    ) -> impl Stream<Item = Result<Episode>> + '_ {
+        let state = ReverseEpisodesState {
+            next: Some(EpisodeParams::with_page(params.series_id, params.page)),
+            started: false,
+            start_page: None,
+        };
+
+        stream::try_unfold(state, move |state| self.reverse_episodes_step(state))
+            .map_ok(|episodes| stream::iter(episodes.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Get all the episodes of a series that have no image.
+    ///
+    /// Requests all the pages of the series' episodes with
+    /// [`series_episodes`] and returns the episodes with no `filename`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let missing = client.series_episodes_missing_images(121361).await?;
+    ///
+    /// for episode in missing {
+    ///     println!("{:?}", episode.episode_name);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_episodes`]: #method.series_episodes
+    pub async fn series_episodes_missing_images<I>(&self, id: I) -> Result<Vec<Episode>>
+    where
+        I: Into<SeriesID>,
+    {
+        let mut episodes = self.all_episodes(id.into()).await?;
+
+        episodes.retain(|e| e.filename.is_none());
+
+        Ok(episodes)
+    }
+
+    /// Get all the episodes of a series that were updated after the given
+    /// time.
+    ///
+    /// Requests all the pages of the series' episodes with
+    /// [`series_episodes`] and returns the episodes with a `last_updated`
+    /// time after `since`. Episodes with no `last_updated` time are not
+    /// included.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let week_ago = Utc::now() - Duration::weeks(1);
+    ///
+    /// let updated = client
+    ///     .series_episodes_updated_since(121361, week_ago)
+    ///     .await?;
+    ///
+    /// for episode in updated {
+    ///     println!("{:?}", episode.episode_name);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_episodes`]: #method.series_episodes
+    pub async fn series_episodes_updated_since<I>(
+        &self,
+        id: I,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<Episode>>
+    where
+        I: Into<SeriesID>,
+    {
+        let mut episodes = self.all_episodes(id.into()).await?;
+
+        episodes.retain(|e| e.last_updated.filter(|u| *u > since).is_some());
+
+        Ok(episodes)
+    }
+
+    /// Get a series' best rated episodes.
+    ///
+    /// Requests all the pages of [`series_episodes`] and returns at most
+    /// `limit` episodes that have a `site_rating`, sorted by rating in
+    /// descending order. Episodes with the same rating are sorted by their
+    /// number of rating votes, also in descending order.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let top_episodes = client.series_top_episodes(121361, 10).await?;
+    ///
+    /// for episode in top_episodes {
+    ///     println!("{:?}: {:?}", episode.episode_name, episode.site_rating);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_episodes`]: #method.series_episodes
+    pub async fn series_top_episodes<I>(&self, id: I, limit: usize) -> Result<Vec<Episode>>
+    where
+        I: Into<SeriesID>,
+    {
+        let mut episodes = self.all_episodes(id.into()).await?;
+
+        episodes.retain(|e| e.site_rating.is_some());
+
+        episodes.sort_by(|a, b| {
+            b.site_rating
+                .partial_cmp(&a.site_rating)
+                .unwrap_or(Ordering::Equal)
+                .then(b.site_rating_count.cmp(&a.site_rating_count))
+        });
+
+        episodes.truncate(limit);
+
+        Ok(episodes)
+    }
+
+    /// Get all of a series' episodes in the order they are meant to be
+    /// watched.
+    ///
+    /// Requests all the pages of [`series_episodes`] and sorts the regular
+    /// episodes by season and episode number. Specials (season `0`) are
+    /// placed using their `airs_before_season`, `airs_before_episode` and
+    /// `airs_after_season` fields. Specials without placement data are placed
+    /// before the first regular episode that aired after them, or at the end
+    /// if their air date is not known.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let episodes = client.series_watch_order(78804).await?;
+    ///
+    /// for episode in episodes {
+    ///     println!(
+    ///         "S{:?}E{}: {:?}",
+    ///         episode.aired_season, episode.aired_episode_number, episode.episode_name
+    ///     );
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_episodes`]: #method.series_episodes
+    pub async fn series_watch_order<I>(&self, id: I) -> Result<Vec<Episode>>
+    where
+        I: Into<SeriesID>,
+    {
+        let episodes = self.all_episodes(id.into()).await?;
+
+        Ok(watch_order(episodes))
+    }
+
+    /// Get how much of a series' data is translated to the given language.
+    ///
+    /// Requests the series and all its episodes in the given language and
+    /// counts the episodes whose name is available in it. See [`Coverage`]
+    /// for more info.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let coverage = client.series_language_coverage(318408, "ko").await?;
+    ///
+    /// println!(
+    ///     "{}/{} episode names translated",
+    ///     coverage.episodes_with_localized_name, coverage.total_episodes
+    /// );
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`Coverage`]: ../response/struct.Coverage.html
+    pub async fn series_language_coverage<I>(&self, id: I, abbr: &str) -> Result<Coverage>
+    where
+        I: Into<SeriesID>,
+    {
+        let id = id.into();
+
+        let series_req = async {
+            let req = self
+                .prep_abbr_req(Method::GET, self.series_url(id), abbr)
+                .await?;
+
+            let res = self.send(req).await?;
+
+            let res = api_errors(res).await?;
+
+            Ok(res.json::<ResponseData<Series>>().await?.data)
+        };
+
+        let episodes_req = async {
+            match self.all_episodes_in(id, Some(abbr)).await {
+                Ok(episodes) => Ok(episodes),
+                // series without episodes
+                Err(Error::NotFound(Some(message))) if message.starts_with(NO_RESULTS_MESSAGE) => {
+                    Ok(Vec::new())
+                }
+                Err(e) => Err(e),
+            }
+        };
+
+        let (series, episodes): (Series, Vec<Episode>) =
+            futures::try_join!(series_req, episodes_req)?;
+
+        let episodes_with_localized_name = episodes
+            .iter()
+            .filter(|e| e.episode_name.is_some() && e.language.episode_name == abbr)
+            .count();
+
+        Ok(Coverage {
+            total_episodes: episodes.len(),
+            episodes_with_localized_name,
+            series_name_localized: series.series_name.is_some() && series.language == abbr,
+        })
+    }
+
+    /// Get a series in the client's language, detecting which fields the API
+    /// filled with English because they are not translated.
+    ///
+    /// The API silently falls back to English for each field that is missing
+    /// in the requested language. To detect this, the series is also
+    /// requested in English, **sending an extra request**, and the
+    /// `series_name` and `overview` of both are compared. Fields that are
+    /// genuinely the same in both languages are also reported as fallbacks.
+    ///
+    /// If the client's language is English, only one request is sent and no
+    /// field is reported as a fallback.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let mut client = Client::new("KEY").await?;
+    /// #
+    /// client.set_language_abbr("de");
+    ///
+    /// let localized = client.series_localized_checked(318408).await?;
+    ///
+    /// if localized.overview_is_fallback {
+    ///     println!("no German overview available");
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    pub async fn series_localized_checked<I>(&self, id: I) -> Result<LocalizedSeries>
+    where
+        I: Into<SeriesID>,
+    {
+        let id = id.into();
+
+        if self.is_default_language() {
+            return Ok(LocalizedSeries {
+                series: self.series(id).await?,
+                name_is_fallback: false,
+                overview_is_fallback: false,
+            });
+        }
+
+        let english_req = async {
+            let req = self
+                .prep_abbr_req(Method::GET, self.series_url(id), DEFAULT_LANG_ABBR)
+                .await?;
+
+            let res = self.send(req).await?;
+
+            let res = api_errors(res).await?;
+
+            Ok(res.json::<ResponseData<Series>>().await?.data)
+        };
+
+        let (series, english): (Series, Series) = futures::try_join!(self.series(id), english_req)?;
+
+        let is_fallback = |localized: &Option<String>, english: &Option<String>| {
+            localized.is_some() && localized == english
+        };
+
+        Ok(LocalizedSeries {
+            name_is_fallback: is_fallback(&series.series_name, &english.series_name),
+            overview_is_fallback: is_fallback(&series.overview, &english.overview),
+            series,
+        })
+    }
+
+    /// Guess a series' original language.
+    ///
+    /// Samples the first page of [`series_episodes`] and returns the most
+    /// common episode name language abbreviation. If the series has no
+    /// episodes with a known name language, the `language` of the
+    /// [`series`] data is returned instead, or `None` if it is empty.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let language = client.series_original_language(318408).await?;
+    ///
+    /// assert_eq!(language, Some("en".to_string()));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_episodes`]: #method.series_episodes
+    /// [`series`]: #method.series
+    pub async fn series_original_language<I>(&self, id: I) -> Result<Option<String>>
+    where
+        I: Into<SeriesID>,
+    {
+        let id = id.into();
+
+        let episodes = match self.series_episodes(&EpisodeParams::new(id)).await {
+            Ok(page) => page.episodes,
+            Err(Error::NotFound(Some(message))) if message.starts_with(NO_RESULTS_MESSAGE) => {
+                Vec::new()
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut counts: Vec<(&str, usize)> = Vec::new();
+
+        for abbr in episodes.iter().map(|e| e.language.episode_name.as_str()) {
+            if abbr.is_empty() {
+                continue;
+            }
+
+            match counts.iter_mut().find(|(a, _)| *a == abbr) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((abbr, 1)),
+            }
+        }
+
+        // on ties, keep the language seen first
+        let most_common = counts.into_iter().fold(None, |best, current| match best {
+            Some((_, count)) if count >= current.1 => best,
+            _ => Some(current),
+        });
+
+        if let Some((abbr, _)) = most_common {
+            return Ok(Some(abbr.to_string()));
+        }
+
+        let series = self.series(id).await?;
+
+        Ok(Some(series.language).filter(|l| !l.is_empty()))
+    }
+
+    /// Get a page of a series' episodes queried with the given params.
+    ///
+    /// Sends a `GET` request to the `/series/{id}/episodes/query` API endpoint.
+    ///
+    /// Check [`series_episodes`] for pagination examples.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use thetvdb::params::EpisodeQueryParams;
+    ///
+    /// let query = EpisodeQueryParams::new(318408)
+    ///     .absolute_number(1)
+    ///     .aired_season(1)
+    ///     .aired_episode(1)
+    ///     .dvd_season(1)
+    ///     .dvd_episode(1);
+    ///
+    /// let episode_page = client.series_episodes_query(&query).await?;
+    ///
+    /// assert_eq!(
+    ///     episode_page.episodes[0].episode_name,
+    ///     Some("Islands".to_string())
+    /// );
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found or the data set is
+    /// empty. Use [`series_episodes_query_opt`] to tell the two apart.
+    ///
+    /// [`series_episodes`]: #method.series_episodes
+    /// [`series_episodes_query_opt`]: #method.series_episodes_query_opt
+    pub async fn series_episodes_query(
+        &self,
+        query_params: &EpisodeQueryParams,
+    ) -> Result<EpisodeQueryPage> {
+        self.series_episodes_query_into(query_params).await
+    }
+
+    /// Same as [`series_episodes_query`], but returns `None` instead of an
+    /// error when no episodes match the query.
+    ///
+    /// The API responds with `404 Not Found` both to queries without results
+    /// and to series that do not exist. The two cases are told apart by the
+    /// error message: empty results are reported as
+    /// `"No results for your query: ..."`, while any other `404` (like the one
+    /// for a missing series) is still returned as [`Error::NotFound`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use thetvdb::params::EpisodeQueryParams;
+    ///
+    /// let query = EpisodeQueryParams::new(318408).aired_season(99);
+    ///
+    /// assert!(client.series_episodes_query_opt(&query).await?.is_none());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_episodes_query`]: #method.series_episodes_query
+    /// [`Error::NotFound`]: ../error/enum.Error.html#variant.NotFound
+    pub async fn series_episodes_query_opt(
+        &self,
+        query_params: &EpisodeQueryParams,
+    ) -> Result<Option<EpisodeQueryPage>> {
+        match self.series_episodes_query(query_params).await {
+            Ok(page) => Ok(Some(page)),
+            Err(Error::NotFound(Some(message))) if message.starts_with(NO_RESULTS_MESSAGE) => {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Same as [`series_episodes_query`], but allows deserializing the response
+    /// episode data into a provided type.
+    ///
+    /// [`series_episodes_query`]: #method.series_episodes_query
+    pub async fn series_episodes_query_into<T>(
+        &self,
+        query_params: &EpisodeQueryParams,
+    ) -> Result<EpisodeQueryPage<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let req = self
+            .prep_lang_req(
+                Method::GET,
+                self.series_episodes_query_url(query_params.params.series_id),
+            )
+            .await?
+            .query(&[("page", query_params.params.page)])
+            .query(&query_params.query);
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        let mut page: EpisodeQueryPage<T> = res.json().await?;
+        page.series_id = query_params.params.series_id;
+        page.requested_page = Some(query_params.params.page);
+        page.query = query_params.query.clone();
+
+        Ok(page)
+    }
+
+    /// Get the keys that can be used to query a series' episodes.
+    ///
+    /// Sends a `GET` request to the `/series/{id}/episodes/query/params` API
+    /// endpoint.
+    ///
+    /// The keys are the names of the API query parameters, like
+    /// `"airedSeason"` or `"dvdEpisode"`, which can be set with the matching
+    /// [`EpisodeQueryParams`] methods.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let keys = client.series_episodes_query_params(318408).await?;
+    ///
+    /// assert!(keys.iter().any(|k| k == "airedSeason"));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`EpisodeQueryParams`]: ../params/struct.EpisodeQueryParams.html
+    pub async fn series_episodes_query_params<I>(&self, id: I) -> Result<Vec<String>>
+    where
+        I: Into<SeriesID>,
+    {
+        self.series_episodes_query_params_into(id).await
+    }
+
+    /// Same as [`series_episodes_query_params`], but allows deserializing the
+    /// response key data into a provided type.
+    ///
+    /// [`series_episodes_query_params`]: #method.series_episodes_query_params
+    pub async fn series_episodes_query_params_into<T, I>(&self, id: I) -> Result<Vec<T>>
+    where
+        I: Into<SeriesID>,
+        T: DeserializeOwned,
+    {
+        let req = self
+            .prep_req(
+                Method::GET,
+                self.series_episodes_query_params_url(id.into()),
+            )
+            .await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+    }
+
+    /// Get a series' episode by its DVD season and episode numbers.
+    ///
+    /// Uses [`series_episodes_query`], but returns `None` instead of an error
+    /// when no episode matches.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let episode = client.episode_by_dvd(318408, 1, 1).await?;
+    ///
+    /// assert_eq!(
+    ///     episode.unwrap().episode_name,
+    ///     Some("Islands".to_string())
+    /// );
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_episodes_query`]: #method.series_episodes_query
+    pub async fn episode_by_dvd<I>(
+        &self,
+        series_id: I,
+        dvd_season: u32,
+        dvd_episode: u32,
+    ) -> Result<Option<Episode>>
+    where
+        I: Into<SeriesID>,
+    {
+        let query = EpisodeQueryParams::new(series_id)
+            .dvd_season(dvd_season)
+            .dvd_episode(dvd_episode);
+
+        let page = self.series_episodes_query_opt(&query).await?;
+
+        Ok(page.and_then(|p| p.episodes.into_iter().next()))
+    }
+
+    /// Get the summary of a series' episodes.
+    ///
+    /// Sends a `GET` request to the `/series/{id}/episodes/summary` API
+    /// endpoint.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let summary = client.series_episodes_summary(318408).await?;
+    ///
+    /// assert_eq!(summary.aired_episodes, 18);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    pub async fn series_episodes_summary<I>(&self, id: I) -> Result<EpisodeSummary>
+    where
+        I: Into<SeriesID>,
+    {
+        self.series_episodes_summary_into(id).await
+    }
+
+    /// Get the episode summaries of many series.
+    ///
+    /// Sends a [`series_episodes_summary`] request for each id, a few of them
+    /// at a time, and returns each summary or error together with its series
+    /// id, in the same order as the ids.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// for (id, summary) in client.series_summaries_many(vec![318408, 121361]).await {
+    ///     match summary {
+    ///         Ok(summary) => println!("{}: {} episodes", id, summary.aired_episodes),
+    ///         Err(e) => eprintln!("{}: {}", id, e),
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`series_episodes_summary`]: #method.series_episodes_summary
+    pub async fn series_summaries_many<T>(&self, ids: T) -> Vec<(SeriesID, Result<EpisodeSummary>)>
+    where
+        T: IntoIterator,
+        T::Item: Into<SeriesID>,
+    {
+        stream::iter(ids.into_iter().map(|id| {
+            let id = id.into();
+
+            async move { (id, self.series_episodes_summary(id).await) }
+        }))
+        .buffered(SERIES_MANY_CONCURRENCY)
+        .collect()
+        .await
+    }
+
+    /// Same as [`series_episodes_summary`], but allows deserializing the
+    /// response episode summary data into a provided type.
+    ///
+    /// [`series_episodes_summary`]: #method.series_episodes_summary
+    pub async fn series_episodes_summary_into<T, I>(&self, id: I) -> Result<T>
+    where
+        I: Into<SeriesID>,
+        T: DeserializeOwned,
+    {
+        let req = self
+            .prep_req(Method::GET, self.series_episodes_summary_url(id.into()))
+            .await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<T>>().await?.data)
+    }
+
+    /// Get the number of aired episodes of a series.
+    ///
+    /// The episode pages returned by [`series_episodes`] do not include the
+    /// total number of episodes, so this method uses the
+    /// [`series_episodes_summary`] instead of fetching every page.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let total = client.series_episode_total(318408).await?;
+    ///
+    /// assert_eq!(total, 18);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_episodes`]: #method.series_episodes
+    /// [`series_episodes_summary`]: #method.series_episodes_summary
+    pub async fn series_episode_total<I>(&self, id: I) -> Result<u32>
+    where
+        I: Into<SeriesID>,
+    {
+        Ok(self.series_episodes_summary(id).await?.aired_episodes)
+    }
+
+    /// Check whether a series looks like a miniseries.
+    ///
+    /// A series is considered a miniseries if all of the following are true:
+    /// * its status is [`SeriesStatus::Ended`]
+    /// * it has exactly one aired season, not counting specials (season `0`)
+    /// * it has at most `max_episodes` aired episodes
+    ///
+    /// The API has no miniseries flag, so this is only a heuristic based on the
+    /// [`series`] and [`series_episodes_summary`] data, which are requested
+    /// concurrently.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let is_miniseries = client.series_is_miniseries(81248, 12).await?;
+    ///
+    /// assert!(is_miniseries);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`SeriesStatus::Ended`]: ../response/enum.SeriesStatus.html#variant.Ended
+    /// [`series`]: #method.series
+    /// [`series_episodes_summary`]: #method.series_episodes_summary
+    pub async fn series_is_miniseries<I>(&self, id: I, max_episodes: u32) -> Result<bool>
+    where
+        I: Into<SeriesID>,
+    {
+        let id = id.into();
+
+        let (series, summary) =
+            futures::try_join!(self.series(id), self.series_episodes_summary(id))?;
+
+        let seasons = summary
+            .aired_seasons
+            .iter()
+            .filter(|s| s.as_str() != "0")
+            .count();
+
+        Ok(series.status == SeriesStatus::Ended
+            && seasons == 1
+            && summary.aired_episodes <= max_episodes)
+    }
+
+    /// Get all the episodes of a series' aired season.
+    ///
+    /// Season `0` contains the series' specials. The season's existence is
+    /// checked using [`series_episodes_summary`], then its episodes are
+    /// requested with [`series_episodes_query`], following all result pages.
+    /// The episodes are sorted by their aired episode number.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let season = client.season(318408, 1).await?;
+    ///
+    /// assert_eq!(season.episode_count, 6);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::NotFound`] if the series is not found or if it
+    /// has no aired season with the given number. A season that exists but
+    /// has no episodes is returned empty.
+    ///
+    /// [`series_episodes_summary`]: #method.series_episodes_summary
+    /// [`series_episodes_query`]: #method.series_episodes_query
+    /// [`Error::NotFound`]: ../error/enum.Error.html#variant.NotFound
+    pub async fn season<I>(&self, series_id: I, season_number: u16) -> Result<Season>
+    where
+        I: Into<SeriesID>,
+    {
+        let series_id = series_id.into();
+
+        let summary = self.series_episodes_summary(series_id).await?;
+
+        if !summary
+            .aired_seasons
+            .iter()
+            .any(|s| s.parse::<u16>().ok() == Some(season_number))
+        {
+            return Err(Error::NotFound(None));
+        }
+
+        let episodes = self
+            .aired_season_episodes(series_id, season_number.into())
+            .await?;
+
+        Ok(Season {
+            number: season_number,
+            episode_count: episodes.len(),
+            episodes,
+        })
+    }
+
+    /// Get an episode together with the previous and next aired episodes of
+    /// its series.
+    ///
+    /// Uses [`episode`], then gets the episodes of the same aired season with
+    /// [`series_episodes_query`] to find the neighbors by aired episode
+    /// number. At the start or end of a season, the last episode of the
+    /// previous season or the first episode of the next season is used
+    /// instead. Specials (season `0`) are never crossed into or out of.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let neighbors = client.episode_with_neighbors(5949345).await?;
+    ///
+    /// if let Some(next) = neighbors.next {
+    ///     println!("Up next: {:?}", next.episode_name);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the episode is not found.
+    ///
+    /// [`episode`]: #method.episode
+    /// [`series_episodes_query`]: #method.series_episodes_query
+    pub async fn episode_with_neighbors<I>(&self, id: I) -> Result<EpisodeNeighbors>
+    where
+        I: Into<EpisodeID>,
+    {
+        let current = self.episode(id).await?;
+
+        let season = match current.aired_season {
+            Some(season) => season,
+            None => {
+                return Ok(EpisodeNeighbors {
+                    current,
+                    previous: None,
+                    next: None,
+                })
+            }
+        };
+
+        let series_id = current.series_id;
+        let number = current.aired_episode_number;
+
+        let episodes = self.aired_season_episodes(series_id, season).await?;
+
+        let mut previous = episodes
+            .iter()
+            .rev()
+            .find(|e| e.aired_episode_number < number)
+            .cloned();
+        let mut next = episodes
+            .into_iter()
+            .find(|e| e.aired_episode_number > number);
+
+        if previous.is_none() && season > 1 {
+            previous = self
+                .aired_season_episodes(series_id, season - 1)
+                .await?
+                .pop();
+        }
+
+        if next.is_none() && season > 0 {
+            next = self
+                .aired_season_episodes(series_id, season + 1)
+                .await?
+                .into_iter()
+                .next();
+        }
+
+        Ok(EpisodeNeighbors {
+            current,
+            previous,
+            next,
+        })
+    }
+
+    /// Get only selected fields of a series.
+    ///
+    /// Sends a `GET` request to the `/series/{id}/filter` API endpoint.
+    ///
+    /// This can be more efficient than getting all the fields.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use thetvdb::params::SeriesFilterKeys;
+    ///
+    /// let keys = SeriesFilterKeys::new().series_name();
+    ///
+    /// let filtered_series = client.series_filter(318408, &keys).await?;
+    ///
+    /// assert_eq!(
+    ///     filtered_series.series_name,
+    ///     Some("Planet Earth II".to_string())
+    /// );
+    ///
+    /// assert_eq!(filtered_series.id, None);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    pub async fn series_filter<I>(
+        &self,
+        id: I,
+        filter_keys: &SeriesFilterKeys,
+    ) -> Result<FilteredSeries>
+    where
+        I: Into<SeriesID>,
+    {
+        self.series_filter_into(id, filter_keys).await
+    }
+
+    /// Get the series that are currently airing among the given ones.
+    ///
+    /// Uses [`series_filter`] to request only the `id`, `status` and
+    /// `series_name` fields of each series, a few series at a time, and
+    /// returns those whose status is [`SeriesStatus::Continuing`], in the
+    /// same order as the ids.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use thetvdb::response::SeriesID;
+    ///
+    /// let airing = client
+    ///     .filter_airing(&[SeriesID(318408), SeriesID(121361)])
+    ///     .await?;
+    ///
+    /// for series in airing {
+    ///     println!("{:?}", series.series_name);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if any of the series is not found.
+    ///
+    /// [`series_filter`]: #method.series_filter
+    /// [`SeriesStatus::Continuing`]: ../response/enum.SeriesStatus.html#variant.Continuing
+    pub async fn filter_airing(&self, ids: &[SeriesID]) -> Result<Vec<FilteredSeries>> {
+        let keys = SeriesFilterKeys::new().id().status().series_name();
+
+        let series: Vec<FilteredSeries> =
+            stream::iter(ids.iter().map(|id| self.series_filter(*id, &keys)))
+                .buffered(SERIES_MANY_CONCURRENCY)
+                .try_collect()
+                .await?;
+
+        Ok(series
+            .into_iter()
+            .filter(|s| s.status == Some(SeriesStatus::Continuing))
+            .collect())
+    }
+
+    /// Same as [`series_filter`], but allows deserializing the response series
+    /// data into a provided type.
+    ///
+    /// [`series_filter`]: #method.series_filter
+    pub async fn series_filter_into<T, I>(&self, id: I, filter_keys: &SeriesFilterKeys) -> Result<T>
+    where
+        I: Into<SeriesID>,
+        T: DeserializeOwned,
+    {
+        if filter_keys.is_empty() {
+            return Err(Error::MissingSeriesFilterKeys);
+        }
+
+        let req = self
+            .prep_lang_req(Method::GET, self.series_filter_url(id.into()))
+            .await?
+            .query(&[("keys", &filter_keys.keys_query)]);
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<T>>().await?.data)
+    }
+
+    /// Get the full URL of a series' poster.
+    ///
+    /// Uses [`series_filter`] to request only the `poster` field of the
+    /// series.
+    ///
+    /// Returns `None` if the series has no poster.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// if let Some(url) = client.series_poster(318408).await? {
+    ///     println!("{}", url);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_filter`]: #method.series_filter
+    pub async fn series_poster<I>(&self, id: I) -> Result<Option<Url>>
+    where
+        I: Into<SeriesID>,
+    {
+        let series = self
+            .series_filter(id, &SeriesFilterKeys::new().poster())
+            .await?;
+
+        match series.poster {
+            Some(_) => series.poster_url().map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Download an image, e.g. from a URL returned by [`series_poster`] or
+    /// [`Image::file_name_url`].
+    ///
+    /// Sends a `GET` request to the given URL, without authentication.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// if let Some(url) = client.series_poster(318408).await? {
+    ///     let bytes = client.fetch_image(&url).await?;
+    ///
+    ///     std::fs::write("poster.jpg", &bytes)?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the image is not found.
+    ///
+    /// [`series_poster`]: #method.series_poster
+    /// [`Image::file_name_url`]: ../response/struct.Image.html#method.file_name_url
+    pub async fn fetch_image(&self, url: &Url) -> Result<Bytes> {
+        let req = self
+            .http_client
+            .get(url.clone())
+            .headers(self.default_headers.clone());
+
+        let res = self.send_with_retry(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.bytes().await?)
+    }
+
+    /// Download the posters of many series.
+    ///
+    /// For each id, gets the poster URL with [`series_poster`] and downloads
+    /// it with [`fetch_image`], a few series at a time. The returned stream
+    /// yields the poster bytes together with the series id, in the same
+    /// order as the ids.
+    ///
+    /// Series without a poster yield [`Error::MissingImage`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use futures::StreamExt;
+    /// use thetvdb::response::SeriesID;
+    ///
+    /// let ids = vec![SeriesID(318408), SeriesID(121361)];
+    /// let mut posters = client.download_posters(&ids);
+    ///
+    /// while let Some((id, poster)) = posters.next().await {
+    ///     match poster {
+    ///         Ok(bytes) => std::fs::write(format!("{}.jpg", id), &bytes)?,
+    ///         Err(e) => eprintln!("no poster for {}: {}", id, e),
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`series_poster`]: #method.series_poster
+    /// [`fetch_image`]: #method.fetch_image
+    /// [`Error::MissingImage`]: ../error/enum.Error.html#variant.MissingImage
+    pub fn download_posters(
+        &self,
+        ids: &[SeriesID],
+    ) -> impl Stream<Item = (SeriesID, Result<Bytes>)> + '_ {
+        stream::iter(ids.to_vec())
+            .map(move |id| async move { (id, self.download_poster(id).await) })
+            .buffered(SERIES_MANY_CONCURRENCY)
+    }
+
+    /// Get a summary of a series' images.
+    ///
+    /// Sends a `GET` request to the `/series/{id}/images` API endpoint.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let image_summary = client.series_images(318408).await?;
+    ///
+    /// assert_eq!(image_summary.poster, Some(8));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    pub async fn series_images<I>(&self, id: I) -> Result<SeriesImages>
+    where
+        I: Into<SeriesID>,
+    {
+        self.series_images_into(id).await
+    }
+
+    /// Same as [`series_images`], but allows deserializing the response image
+    /// summary data into a provided type.
+    ///
+    /// [`series_images`]: #method.series_images
+    pub async fn series_images_into<T, I>(&self, id: I) -> Result<T>
+    where
+        I: Into<SeriesID>,
+        T: DeserializeOwned,
+    {
+        let req = self
+            .prep_lang_req(Method::GET, self.series_images_url(id.into()))
+            .await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<T>>().await?.data)
+    }
+
+    /// Get a summary of a series' images along with its best rated poster.
+    ///
+    /// Requests [`series_images`] and the series' posters with
+    /// [`series_images_query`] concurrently. The poster with the highest
+    /// average rating is returned, or `None` if the series has no posters.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let (summary, poster) = client.series_images_with_primary(318408).await?;
+    ///
+    /// println!("{:?} posters", summary.poster);
+    ///
+    /// if let Some(poster) = poster {
+    ///     println!("{}", poster.file_name_url()?);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_images`]: #method.series_images
+    /// [`series_images_query`]: #method.series_images_query
+    pub async fn series_images_with_primary<I>(
+        &self,
+        id: I,
+    ) -> Result<(SeriesImages, Option<Image>)>
+    where
+        I: Into<SeriesID>,
+    {
+        let id = id.into();
+        let params = ImageQueryParams::with_key_type("poster");
+
+        let (summary, posters) = futures::try_join!(self.series_images(id), async {
+            match self.series_images_query(id, &params).await {
+                Ok(posters) => Ok(posters),
+                // the API responds with 404 when there are no posters
+                Err(Error::NotFound(Some(message))) if message.starts_with(NO_RESULTS_MESSAGE) => {
+                    Ok(Vec::new())
+                }
+                Err(e) => Err(e),
+            }
+        })?;
+
+        let poster = posters.into_iter().max_by(|a, b| {
+            a.ratings_info
+                .average
+                .partial_cmp(&b.ratings_info.average)
+                .unwrap_or(Ordering::Equal)
+                .then(a.ratings_info.count.cmp(&b.ratings_info.count))
+        });
+
+        Ok((summary, poster))
+    }
+
+    /// Get a series' images based on query parameters.
+    ///
+    /// Sends a `GET` request to the `/series/{id}/images/query` API endpoint.
+    ///
+    /// Each series may have different available image types.
+    /// To find out which key types, resolutions and subkeys are available for
+    /// a series use the [`series_images_query_params`] method.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use thetvdb::params::ImageQueryParams;
+    ///
+    /// let params = ImageQueryParams::with_key_type("poster");
+    ///
+    /// let images = client.
+    ///     series_images_query(318408, &params)
+    ///     .await?;
+    ///
+    /// assert_eq!(images.len(), 8);
+    ///
+    /// // print an image's URL
+    /// println!("{}", images[0].file_name_url()?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found or the data set is
+    /// empty.
+    ///
+    /// [`series_images_query_params`]: #method.series_images_query_params
+    pub async fn series_images_query<I>(
+        &self,
+        id: I,
+        params: &ImageQueryParams,
+    ) -> Result<Vec<Image>>
+    where
+        I: Into<SeriesID>,
+    {
+        self.series_images_query_into(id, params).await
+    }
+
+    /// Same as [`series_images_query`], but allows deserializing the response
+    /// image data into a provided type.
+    ///
+    /// [`series_images_query`]: #method.series_images_query
+    pub async fn series_images_query_into<T, I>(
+        &self,
+        id: I,
+        params: &ImageQueryParams,
+    ) -> Result<Vec<T>>
+    where
+        I: Into<SeriesID>,
+        T: DeserializeOwned,
+    {
+        let req = self
+            .prep_lang_req(Method::GET, self.series_images_query_url(id.into()))
+            .await?
+            .query(&params);
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+    }
+
+    /// Get a series' images based on query parameters, only in the given
+    /// language.
+    ///
+    /// Same as [`series_images_query`], but the language abbreviation is sent
+    /// instead of the client's and images in other languages, which the API
+    /// may still return, are filtered out.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use thetvdb::params::ImageQueryParams;
+    ///
+    /// let params = ImageQueryParams::with_key_type("poster");
+    ///
+    /// let images = client
+    ///     .series_images_query_in_language(318408, &params, "de")
+    ///     .await?;
+    ///
+    /// assert!(images.iter().all(|i| i.language == "de"));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found or the data set is
+    /// empty.
+    ///
+    /// [`series_images_query`]: #method.series_images_query
+    pub async fn series_images_query_in_language<I>(
+        &self,
+        id: I,
+        params: &ImageQueryParams,
+        lang_abbr: &str,
+    ) -> Result<Vec<Image>>
+    where
+        I: Into<SeriesID>,
+    {
+        let req = self
+            .prep_abbr_req(
+                Method::GET,
+                self.series_images_query_url(id.into()),
+                lang_abbr,
+            )
+            .await?
+            .query(&params);
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        let mut images = res.json::<ResponseData<Vec<Image>>>().await?.data;
+
+        images.retain(|i| i.language == lang_abbr);
+
+        Ok(images)
+    }
+
+    /// Get a series' available image key types, resolutions and subkeys.
+    ///
+    /// Sends a `GET` request to the `/series/{id}/images/query/params` API
+    /// endpoint.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let image_keys = client
+    ///     .series_images_query_params(318408)
+    ///     .await?;
+    ///
+    /// // print resolutions available for the first image key type
+    /// println!("{:#?}", image_keys[0].resolution);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    pub async fn series_images_query_params<I>(&self, id: I) -> Result<Vec<ImageQueryKey>>
+    where
+        I: Into<SeriesID>,
+    {
+        self.series_images_query_params_into(id).await
+    }
+
+    /// Same as [`series_images_query_params`], but allows deserializing the
+    /// response image key data into a provided type.
+    ///
+    /// [`series_images_query_params`]: #method.series_images_query_params
+    pub async fn series_images_query_params_into<T, I>(&self, id: I) -> Result<Vec<T>>
+    where
+        I: Into<SeriesID>,
+        T: DeserializeOwned,
+    {
+        let req = self
+            .prep_lang_req(Method::GET, self.series_images_query_params_url(id.into()))
+            .await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+    }
+
+    /// Get the ids of the languages a series has images in.
+    ///
+    /// Uses the key data returned by [`series_images_query_params`] and
+    /// returns the distinct language ids found, in ascending order. Language
+    /// ids that cannot be parsed are skipped.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let language_ids = client.series_image_languages(318408).await?;
+    ///
+    /// for id in language_ids {
+    ///     println!("{}", id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found.
+    ///
+    /// [`series_images_query_params`]: #method.series_images_query_params
+    pub async fn series_image_languages<I>(&self, id: I) -> Result<Vec<LanguageID>>
+    where
+        I: Into<SeriesID>,
+    {
+        let mut ids: Vec<LanguageID> = self
+            .series_images_query_params(id)
+            .await?
+            .iter()
+            .filter_map(|k| k.language_id.as_ref())
+            .filter_map(|l| l.parse().ok().map(LanguageID))
+            .collect();
+
+        ids.sort();
+        ids.dedup();
+
+        Ok(ids)
+    }
+
+    /// Get a series' images of the given key type in every available
+    /// resolution.
+    ///
+    /// The resolutions are read from [`series_images_query_params`] and a
+    /// [`series_images_query`] request is sent for each of them. Images
+    /// returned for more than one resolution are only included once.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let fanart = client
+    ///     .series_images_all_resolutions(318408, "fanart")
+    ///     .await?;
+    ///
+    /// for image in fanart {
+    ///     println!("{:?}: {}", image.resolution, image.file_name_url()?);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the series is not found or if the key type
+    /// has no images in one of its resolutions.
+    ///
+    /// [`series_images_query_params`]: #method.series_images_query_params
+    /// [`series_images_query`]: #method.series_images_query
+    pub async fn series_images_all_resolutions<I>(
+        &self,
+        id: I,
+        key_type: &str,
+    ) -> Result<Vec<Image>>
+    where
+        I: Into<SeriesID>,
+    {
+        let id = id.into();
+
+        let mut resolutions: Vec<String> = self
+            .series_images_query_params(id)
+            .await?
+            .into_iter()
+            .filter(|k| k.key_type == key_type)
+            .flat_map(|k| k.resolution)
+            .collect();
+
+        resolutions.sort();
+        resolutions.dedup();
+
+        let mut images: Vec<Image> = Vec::new();
+
+        for resolution in resolutions {
+            let params = ImageQueryParams::with_key_type(key_type).resolution(resolution);
+
+            for image in self.series_images_query(id, &params).await? {
+                if images.iter().all(|i| i.id != image.id) {
+                    images.push(image);
+                }
+            }
+        }
+
+        Ok(images)
+    }
+
+    /// Get an episode by its id.
+    ///
+    /// Sends a `GET` request to the `/episodes/{id}` API endpoint.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result, response::SeriesID};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let episode = client.episode(5812389).await?;
+    ///
+    /// assert_eq!(episode.episode_name, Some("Islands".to_string()));
+    /// assert_eq!(episode.series_id, SeriesID(318408));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the episode is not found.
+    pub async fn episode<I>(&self, id: I) -> Result<Episode>
+    where
+        I: Into<EpisodeID>,
+    {
+        self.episode_into(id).await
+    }
+
+    /// Same as [`episode`], but allows deserializing the response episode data
+    /// into a provided type.
+    ///
+    /// [`episode`]: #method.episode
+    pub async fn episode_into<T, I>(&self, id: I) -> Result<T>
+    where
+        I: Into<EpisodeID>,
+        T: DeserializeOwned,
+    {
+        let id = id.into();
+
+        let req = self
+            .prep_lang_req(Method::GET, self.episodes_url(id))
+            .await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        self.data_with_id(res, id.0.into()).await
+    }
+
+    /// Get a episode's data both as [`Episode`] and as raw JSON.
+    ///
+    /// The response is only parsed once, so the raw JSON always matches the
+    /// returned `Episode`. Useful for storing the raw data while also using the
+    /// typed value.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let (episode, raw) = client.episode_with_raw(5812389).await?;
+    ///
+    /// assert_eq!(raw["airedSeason"], 1);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the episode is not found or if the raw data is
+    /// not a valid `Episode`.
+    ///
+    /// [`Episode`]: ../response/struct.Episode.html
+    pub async fn episode_with_raw<I>(&self, id: I) -> Result<(Episode, serde_json::Value)>
+    where
+        I: Into<EpisodeID>,
+    {
+        let raw: serde_json::Value = self.episode_into(id).await?;
+
+        let episode = Episode::deserialize(&raw)?;
+
+        Ok((episode, raw))
+    }
+
+    /// Get the last modified time of an episode.
+    ///
+    /// Sends a `HEAD` request to the `/episodes/{id}` API endpoint.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use chrono::Utc;
+    ///
+    /// let last_modified = client.episode_last_modified(5812389).await?;
+    ///
+    /// assert!(last_modified < Utc::now());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the episode is not found.
+    pub async fn episode_last_modified<I>(&self, id: I) -> Result<DateTime<Utc>>
+    where
+        I: Into<EpisodeID>,
+    {
+        let req = self
+            .prep_req(Method::HEAD, self.episodes_url(id.into()))
+            .await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        last_modified(&res)
+    }
+
+    /// Get an episode only if it was modified after the given time.
+    ///
+    /// Sends a `HEAD` request to the `/episodes/{id}` API endpoint and, only
+    /// if the episode was modified since `since`, a `GET` request to the same
+    /// endpoint.
+    ///
+    /// Returns `None` if the episode was not modified.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use chrono::DateTime;
+    ///
+    /// let since = DateTime::parse_from_rfc3339("2020-01-20T00:00:00-00:00")?;
+    ///
+    /// if let Some(episode) = client.episode_if_modified_since(5812389, since).await? {
+    ///     println!("Episode changed: {:#?}", episode);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the episode is not found.
+    pub async fn episode_if_modified_since<I, D>(&self, id: I, since: D) -> Result<Option<Episode>>
+    where
+        I: Into<EpisodeID>,
+        D: Into<DateTime<Utc>>,
+    {
+        let id = id.into();
+
+        if self.episode_last_modified(id).await? <= since.into() {
+            return Ok(None);
+        }
+
+        self.episode(id).await.map(Some)
+    }
+
+    /// Get the language with the given abbreviation.
+    ///
+    /// The list of available languages is requested using [`languages`]
+    /// the first time it is needed and cached afterwards (see
+    /// [`ClientBuilder::preload_languages`]).
+    ///
+    /// Returns `None` if no language has the given abbreviation.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let mut client = Client::new("KEY").await?;
+    /// #
+    /// let german = client.language_by_abbr("de").await?.unwrap();
+    ///
+    /// client.set_language(&german);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the languages are not cached and the request
+    /// fails.
+    ///
+    /// [`languages`]: #method.languages
+    /// [`ClientBuilder::preload_languages`]: struct.ClientBuilder.html#method.preload_languages
+    pub async fn language_by_abbr(&self, abbr: &str) -> Result<Option<Language>> {
+        let languages = self.cached_languages().await?;
+
+        Ok(languages
+            .as_ref()
+            .expect("missing languages although cached")
+            .iter()
+            .find(|l| l.abbreviation == abbr)
+            .cloned())
+    }
+
+    /// Get the languages with the given ids, in the same order.
+    ///
+    /// Uses the same cache as [`language_by_abbr`]. Ids that do not match
+    /// any language result in `None`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result, language::LanguageID};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let ids = client.series_image_languages(318408).await?;
+    ///
+    /// for language in client.languages_by_ids(&ids).await?.into_iter().flatten() {
+    ///     println!("{}", language.english_name);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the languages are not cached and the request
+    /// fails.
+    ///
+    /// [`language_by_abbr`]: #method.language_by_abbr
+    pub async fn languages_by_ids(&self, ids: &[LanguageID]) -> Result<Vec<Option<Language>>> {
+        let languages = self.cached_languages().await?;
+        let languages = languages
+            .as_ref()
+            .expect("missing languages although cached");
+
+        Ok(ids
+            .iter()
+            .map(|id| languages.iter().find(|l| l.id == *id).cloned())
+            .collect())
+    }
+
+    /// Get all the available languages, mapped by their ids.
+    ///
+    /// Uses the same cache as [`language_by_abbr`]. The returned map can be
+    /// stored and used without the client.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result, language::LanguageID};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let languages = client.language_map().await?;
+    ///
+    /// assert_eq!(languages[&LanguageID(7)].abbreviation, "en");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the languages are not cached and the request
+    /// fails.
+    ///
+    /// [`language_by_abbr`]: #method.language_by_abbr
+    pub async fn language_map(&self) -> Result<HashMap<LanguageID, Language>> {
+        let languages = self.cached_languages().await?;
+
+        Ok(languages
+            .as_ref()
+            .expect("missing languages although cached")
+            .iter()
+            .map(|l| (l.id, l.clone()))
+            .collect())
+    }
+
+    /// Get all the available languages, mapped by their abbreviations.
+    ///
+    /// Works like [`language_map`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let languages = client.language_abbr_map().await?;
+    ///
+    /// assert_eq!(languages["en"].english_name, "English");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the languages are not cached and the request
+    /// fails.
+    ///
+    /// [`language_map`]: #method.language_map
+    pub async fn language_abbr_map(&self) -> Result<HashMap<String, Language>> {
+        let languages = self.cached_languages().await?;
+
+        Ok(languages
+            .as_ref()
+            .expect("missing languages although cached")
+            .iter()
+            .map(|l| (l.abbreviation.clone(), l.clone()))
+            .collect())
+    }
+
+    /// Get a list of all the available languages.
+    ///
+    /// Sends a `GET` request to the `/languages` API endpoint.
+    ///
+    /// Languages can be used to [`set_language`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let languages = client.languages().await?;
+    ///
+    /// // print all the languages
+    /// println!("{:#?}", languages);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`set_language`]: #method.set_language
+    pub async fn languages(&self) -> Result<Vec<Language>> {
+        self.languages_into().await
+    }
+
+    /// Same as [`languages`], but allows deserializing the response language
+    /// data into a provided type.
+    ///
+    /// [`languages`]: #method.languages
+    pub async fn languages_into<T>(&self) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let req = self.prep_req(Method::GET, self.languages_url()).await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+    }
+
+    /// Get a language by its id.
+    ///
+    /// Sends a `GET` request to the `/languages/{id}` API endpoint.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let japanese = client.language(25).await?;
+    ///
+    /// assert_eq!(japanese.abbreviation, "ja".to_string());
+    /// assert_eq!(japanese.name, "日本語".to_string());
+    /// assert_eq!(japanese.english_name, "Japanese".to_string());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the language is not found.
+    pub async fn language<I>(&self, id: I) -> Result<Language>
+    where
+        I: Into<LanguageID>,
+    {
+        self.language_into(id).await
+    }
+
+    /// Same as [`language`], but allows deserializing the response language
+    /// data into a provided type.
+    ///
+    /// [`language`]: #method.language
+    pub async fn language_into<T, I>(&self, id: I) -> Result<T>
+    where
+        I: Into<LanguageID>,
+        T: DeserializeOwned,
+    {
+        let id = id.into();
+
+        let req = self.prep_req(Method::GET, self.language_url(id)).await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        self.data_with_id(res, id.0.into()).await
+    }
+
+    /// Get a list of series updated within a given time period.
+    ///
+    /// Sends a `GET` request to the `/updated/query` API endpoint.
+    ///
+    /// If `to_time` is not set or more than one week after `from_time`, the API
+    /// sets the timespan to one week.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use thetvdb::params::UpdatedParams;
+    /// use chrono::DateTime;
+    ///
+    /// let from = DateTime::parse_from_rfc3339("2019-11-10T12:00:00-00:00")?;
+    /// let to = DateTime::parse_from_rfc3339("2019-11-10T12:10:00-00:00")?;
+    ///
+    /// let timespan = UpdatedParams::with_to_time(from, to);
+    ///
+    /// let updates = client.updated(&timespan).await?;
+    ///
+    /// assert_eq!(updates.len(), 7);
+    ///
+    /// // results can be used to fetch full series data
+    /// let series = client.series(&updates[0]).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if there are no updated series within the
+    /// given timespan.
+    pub async fn updated(&self, params: &UpdatedParams) -> Result<Vec<SeriesUpdate>> {
+        self.updated_into(params).await
+    }
+
+    /// Same as [`updated`], but allows deserializing the response series
+    /// updated data into a provided type.
+    ///
+    /// [`updated`]: #method.updated
+    pub async fn updated_into<T>(&self, params: &UpdatedParams) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let req = self
+            .prep_lang_req(Method::GET, self.updated_url())
+            .await?
+            .query(&params);
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+    }
+
+    /// Get the time each series was last updated within the given timespan.
+    ///
+    /// Unlike [`updated`], timespans longer than a week are allowed: they are
+    /// requested one week at a time. If `params` has no `to` time, updates
+    /// until now are requested.
+    ///
+    /// Series updated more than once are included only once, with their
+    /// latest update time, which makes the result usable as a checkpoint for
+    /// incremental syncing. Timespans without updates result in an empty map.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use chrono::{Duration, Utc};
+    /// use thetvdb::params::UpdatedParams;
+    ///
+    /// let params = UpdatedParams::new(Utc::now() - Duration::weeks(3));
+    ///
+    /// for (id, last_updated) in client.updated_map(&params).await? {
+    ///     println!("series {} updated at {}", id, last_updated);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`updated`]: #method.updated
+    pub async fn updated_map(
+        &self,
+        params: &UpdatedParams,
+    ) -> Result<HashMap<SeriesID, DateTime<Utc>>> {
+        let to = params.to_time.as_ref().map_or_else(Utc::now, |to| to.0);
+
+        Ok(self
+            .updated_range(params.from_time, to)
+            .await?
+            .into_iter()
+            .map(|u| (u.id, u.last_updated))
+            .collect())
+    }
+
+    /// Get a list of series updated within a timespan of any length.
+    ///
+    /// The timespan is split into week-long windows, the longest the API
+    /// allows, which are requested with [`updated`] a few at a time. Windows
+    /// without updates, for which the API responds with `404 Not Found`,
+    /// are treated as empty.
+    ///
+    /// Series updated more than once are included only once, with their
+    /// latest update time. The results are sorted by update time.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let now = Utc::now();
+    ///
+    /// let updates = client.updated_range(now - Duration::days(30), now).await?;
+    ///
+    /// println!("{} series updated in the last month", updates.len());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`updated`]: #method.updated
+    pub async fn updated_range<D>(&self, from: D, to: D) -> Result<Vec<SeriesUpdate>>
+    where
+        D: Into<DateTime<Utc>>,
+    {
+        let mut updates = self.updated_chunked(from.into(), to.into()).await?;
+
+        // keep only the latest update of each series
+        updates.sort_by(|a, b| a.id.cmp(&b.id).then(b.last_updated.cmp(&a.last_updated)));
+        updates.dedup_by_key(|u| u.id);
+
+        updates.sort_by_key(|u| (u.last_updated, u.id));
+
+        Ok(updates)
+    }
+
+    /// Get the ids of all the series and movies updated since the given time.
+    ///
+    /// Series updates are requested with [`updated`], one week at a time
+    /// since that is the longest timespan the API allows, from `since` until
+    /// now. Movie updates are requested with [`movie_updates`].
+    ///
+    /// The returned ids are sorted and without duplicates.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let changes = client
+    ///     .changes_since(Utc::now() - Duration::weeks(3))
+    ///     .await?;
+    ///
+    /// println!("{} series changed", changes.series.len());
+    /// println!("{} movies changed", changes.movies.len());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`updated`]: #method.updated
+    /// [`movie_updates`]: #method.movie_updates
+    pub async fn changes_since<D>(&self, since: D) -> Result<Changes>
+    where
+        D: Into<DateTime<Utc>>,
+    {
+        let since = since.into();
+
+        let (series, movie_updates) = futures::try_join!(
+            self.updated_chunked(since, Utc::now()),
+            self.movie_updates(since)
+        )?;
+
+        let mut series: Vec<SeriesID> = series.into_iter().map(|s| s.id).collect();
+        series.sort();
+        series.dedup();
+
+        let mut movies = movie_updates.movies;
+        movies.sort();
+        movies.dedup();
+
+        Ok(Changes { series, movies })
+    }
+
+    /// Get a movie by its id.
+    ///
+    /// Sends a `GET` request to the `/movies/{id}` API endpoint.
+    ///
+    /// References to `Movie` or any type that impls `Into<MovieID>` can also
+    /// be used for ids.
+    ///
+    /// The API does not support looking up movies by IMDb ID or other
+    /// external ids, so a TheTVDB `MovieID` is required. Use
+    /// [`Movie::imdb_id`] or [`Movie::has_imdb_id`] to match fetched movies
+    /// (e.g. from [`movie_updates`]) against known IMDb IDs.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let movie = client.movie(190).await?;
+    ///
+    /// assert_eq!(movie.runtime, 142);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Movie::imdb_id`]: ../response/struct.Movie.html#method.imdb_id
+    /// [`Movie::has_imdb_id`]: ../response/struct.Movie.html#method.has_imdb_id
+    /// [`movie_updates`]: #method.movie_updates
+    pub async fn movie<I>(&self, id: I) -> Result<Movie>
+    where
+        I: Into<MovieID>,
+    {
+        let movie: Movie = self.movie_into(id).await?;
+
+        self.cache_genres(&movie.genres);
+
+        Ok(movie)
+    }
+
+    /// Same as [`movie`], but allows deserializing the response movie data into
+    /// a provided type.
+    ///
+    /// [`movie`]: #method.movie
+    pub async fn movie_into<T, I>(&self, id: I) -> Result<T>
+    where
+        I: Into<MovieID>,
+        T: DeserializeOwned,
+    {
+        let id = id.into();
+
+        let req = self.prep_lang_req(Method::GET, self.movies_url(id)).await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        self.data_with_id(res, id.0.into()).await
+    }
+
+    /// Get a movie's data as raw JSON.
+    ///
+    /// Same as [`movie_into`] with `serde_json::Value`. Can be used to access
+    /// movie fields returned by the API that [`Movie`] does not have (yet).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let movie = client.movie_raw(190).await?;
+    ///
+    /// assert_eq!(movie["runtime"], 142);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the movie is not found.
+    ///
+    /// [`movie_into`]: #method.movie_into
+    /// [`Movie`]: ../response/struct.Movie.html
+    pub async fn movie_raw<I>(&self, id: I) -> Result<serde_json::Value>
+    where
+        I: Into<MovieID>,
+    {
+        self.movie_into(id).await
+    }
+
+    /// Get a movie's data both as [`Movie`] and as raw JSON.
+    ///
+    /// The response is only parsed once, so the raw JSON always matches the
+    /// returned `Movie`. Useful for storing the raw data while also using the
+    /// typed value.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let (movie, raw) = client.movie_with_raw(190).await?;
+    ///
+    /// assert_eq!(raw["runtime"], 142);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if the movie is not found or if the raw data is
+    /// not a valid `Movie`.
+    ///
+    /// [`Movie`]: ../response/struct.Movie.html
+    pub async fn movie_with_raw<I>(&self, id: I) -> Result<(Movie, serde_json::Value)>
+    where
         I: Into<MovieID>,
     {
-        self.movie_into(id).await
+        let raw: serde_json::Value = self.movie_into(id).await?;
+
+        let movie = Movie::deserialize(&raw)?;
+
+        self.cache_genres(&movie.genres);
+
+        Ok((movie, raw))
+    }
+
+    /// Match a series' genre names against the movie genres known by the
+    /// client.
+    ///
+    /// The API does not provide a list of genres, so the client remembers the
+    /// genres of the movies requested with [`movie`] or [`movie_with_raw`].
+    /// Series genre names are compared to them ignoring case, and `None` is
+    /// returned for names that do not match any known genre.
+    ///
+    /// No genres are known until a movie has been requested, so on a new
+    /// client every entry is `None`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let _ = client.movie(190).await?;
+    ///
+    /// let series = client.series(318408).await?;
+    ///
+    /// for genre in client.resolve_series_genres(&series).into_iter().flatten() {
+    ///     println!("{}", genre.full_url()?);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`movie`]: #method.movie
+    /// [`movie_with_raw`]: #method.movie_with_raw
+    pub fn resolve_series_genres(&self, series: &Series) -> Vec<Option<Genre>> {
+        let genres = self.genres.lock().expect("genre cache lock poisoned");
+
+        series
+            .genre
+            .iter()
+            .map(|name| {
+                genres
+                    .iter()
+                    .find(|g| g.name.eq_ignore_ascii_case(name))
+                    .cloned()
+            })
+            .collect()
+    }
+
+    /// Get a list of movies updated since the given time.
+    ///
+    /// Sends a `GET` request to the `/movieupdates` API endpoint.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// use chrono::DateTime;
+    ///
+    /// let since = DateTime::parse_from_rfc3339("2020-01-20T00:00:00-00:00")?;
+    ///
+    /// let movie_updates = client.movie_updates(since).await?;
+    ///
+    /// println!("Movie IDs updated since 2020-01-20: {:?}", movie_updates.movies);
+    /// # Ok(())}
+    /// ```
+    pub async fn movie_updates<D>(&self, since: D) -> Result<MovieUpdates>
+    where
+        D: Into<DateTime<Utc>>,
+    {
+        self.movie_updates_into(since).await
+    }
+
+    /// Same as [`movie_updates`], but allows deserializing the response data
+    /// into a provided type.
+    ///
+    /// Unlike the other endpoints, `/movieupdates` doesn't wrap its response
+    /// in a `data` field, so the provided type is used for the whole response
+    /// body (e.g. `{"movies": [1, 2, 3]}`).
+    ///
+    /// [`movie_updates`]: #method.movie_updates
+    pub async fn movie_updates_into<T, D>(&self, since: D) -> Result<T>
+    where
+        D: Into<DateTime<Utc>>,
+        T: DeserializeOwned,
+    {
+        let req = self
+            .prep_req(Method::GET, self.movie_updates_url())
+            .await?
+            .query(&[("since", since.into().timestamp())]);
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<T>().await?)
+    }
+
+    /// Get the ids of the user's favorite series.
+    ///
+    /// Sends a `GET` request to the `/user/favorites` API endpoint.
+    ///
+    /// Requires a client authenticated as a user, see [`new_user`]. Returns
+    /// an empty list if the user has no favorites.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::new_user("KEY", "USERNAME", "USER_KEY").await?;
+    ///
+    /// for id in client.user_favorites().await? {
+    ///     println!("{:?}", client.series(id).await?.series_name);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::InvalidAPIKey`] if the client is not
+    /// authenticated as a user.
+    ///
+    /// [`new_user`]: #method.new_user
+    /// [`Error::InvalidAPIKey`]: ../error/enum.Error.html#variant.InvalidAPIKey
+    pub async fn user_favorites(&self) -> Result<Vec<SeriesID>> {
+        let req = self
+            .prep_req(Method::GET, self.user_favorites_url())
+            .await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res
+            .json::<ResponseData<UserFavorites>>()
+            .await?
+            .data
+            .favorites)
+    }
+
+    /// Get all the ratings given by the user.
+    ///
+    /// Sends a `GET` request to the `/user/ratings` API endpoint.
+    ///
+    /// Requires a client authenticated as a user, see [`new_user`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::new_user("KEY", "USERNAME", "USER_KEY").await?;
+    ///
+    /// for rating in client.user_ratings().await? {
+    ///     println!(
+    ///         "{:?} {}: {}",
+    ///         rating.rating_type, rating.rating_item_id, rating.rating
+    ///     );
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::InvalidAPIKey`] if the client is not
+    /// authenticated as a user.
+    ///
+    /// [`new_user`]: #method.new_user
+    /// [`Error::InvalidAPIKey`]: ../error/enum.Error.html#variant.InvalidAPIKey
+    pub async fn user_ratings(&self) -> Result<Vec<Rating>> {
+        self.user_ratings_into().await
+    }
+
+    /// Same as [`user_ratings`], but allows deserializing the response rating
+    /// data into a provided type.
+    ///
+    /// [`user_ratings`]: #method.user_ratings
+    pub async fn user_ratings_into<T>(&self) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let req = self.prep_req(Method::GET, self.user_ratings_url()).await?;
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<Vec<T>>>().await?.data)
+    }
+
+    /// Rate a series, episode or image, replacing any previous rating of the
+    /// user.
+    ///
+    /// Sends a `PUT` request to the
+    /// `/user/ratings/{itemType}/{itemId}/{itemRating}` API endpoint.
+    ///
+    /// Requires a client authenticated as a user, see [`new_user`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// use thetvdb::params::RatingItemType;
+    ///
+    /// let client = Client::new_user("KEY", "USERNAME", "USER_KEY").await?;
+    ///
+    /// client.add_rating(RatingItemType::Series, 318408, 10).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::InvalidRating`] without sending a request if
+    /// `rating` is not between `1` and `10`.
+    ///
+    /// [`new_user`]: #method.new_user
+    /// [`Error::InvalidRating`]: ../error/enum.Error.html#variant.InvalidRating
+    pub async fn add_rating(&self, item_type: RatingItemType, id: u32, rating: u8) -> Result<()> {
+        if !(1..=10).contains(&rating) {
+            return Err(Error::InvalidRating(rating));
+        }
+
+        let url = self.user_rating_url(item_type, id, Some(rating));
+
+        let req = self.prep_req(Method::PUT, url).await?;
+
+        let res = self.send(req).await?;
+
+        api_errors(res).await?;
+
+        Ok(())
+    }
+
+    /// Remove the user's rating of a series, episode or image.
+    ///
+    /// Sends a `DELETE` request to the `/user/ratings/{itemType}/{itemId}`
+    /// API endpoint.
+    ///
+    /// Requires a client authenticated as a user, see [`new_user`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// use thetvdb::params::RatingItemType;
+    ///
+    /// let client = Client::new_user("KEY", "USERNAME", "USER_KEY").await?;
+    ///
+    /// client.remove_rating(RatingItemType::Episode, 5949345).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`new_user`]: #method.new_user
+    pub async fn remove_rating(&self, item_type: RatingItemType, id: u32) -> Result<()> {
+        let url = self.user_rating_url(item_type, id, None);
+
+        let req = self.prep_req(Method::DELETE, url).await?;
+
+        let res = self.send(req).await?;
+
+        api_errors(res).await?;
+
+        Ok(())
+    }
+
+    /// Send a `GET` request to any API endpoint and deserialize the `data` of
+    /// its response into a provided type.
+    ///
+    /// Can be used for endpoints this crate does not support yet. The `path`
+    /// is relative to the API's base URL. Authentication and errors are
+    /// handled like for all other requests, and the client's language is sent
+    /// if `with_lang` is `true`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let query_params: Vec<String> = client
+    ///     .get_into("/series/318408/episodes/query/params", false)
+    ///     .await?;
+    ///
+    /// println!("{:?}", query_params);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// Will return an error if `path` cannot be parsed into an URL or points
+    /// outside the API's base URL, if the resource is not found or if the response data does not match the
+    /// provided type.
+    pub async fn get_into<T>(&self, path: &str, with_lang: bool) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self.endpoint_url(path)?;
+
+        let req = if with_lang {
+            self.prep_lang_req(Method::GET, url).await?
+        } else {
+            self.prep_req(Method::GET, url).await?
+        };
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        Ok(res.json::<ResponseData<T>>().await?.data)
+    }
+
+    // joins `path` to the base URL, making sure the result stays on the API
+    // host so the auth token is never sent anywhere else
+    fn endpoint_url(&self, path: &str) -> Result<Url> {
+        if path.starts_with("//") || Url::parse(path).is_ok() {
+            return Err(Error::InvalidUrl(URLParseError::RelativeUrlWithoutBase));
+        }
+
+        let url = self.base_url.join(path.trim_start_matches('/'))?;
+
+        if url.origin() != self.base_url.origin() || !url.path().starts_with(self.base_url.path()) {
+            return Err(Error::InvalidUrl(URLParseError::RelativeUrlWithoutBase));
+        }
+
+        Ok(url)
+    }
+
+    fn create<S>(api_key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::create_with_http_client(api_key, HttpClient::new())
+    }
+
+    fn create_with_http_client<S>(api_key: S, http_client: HttpClient) -> Self
+    where
+        S: Into<String>,
+    {
+        Client {
+            base_url: Url::parse(BASE_URL).expect("could not parse BASE_URL"),
+            api_key: api_key.into(),
+            token: Arc::new(Mutex::new(None)),
+            http_client,
+            lang_abbr: DEFAULT_LANG_ABBR.to_string(),
+            languages: Arc::new(Mutex::new(None)),
+            genres: Arc::new(std::sync::Mutex::new(Vec::new())),
+            default_headers: HeaderMap::new(),
+            accept: accept_header(DEFAULT_API_VERSION).expect("invalid default Accept header"),
+            request_semaphore: None,
+            verify_ids: false,
+            always_send_language: false,
+            retry: None,
+            on_retry: None,
+            manage_token: true,
+            timeout: None,
+            user: None,
+        }
+    }
+
+    async fn first_search_result<S>(&self, param: SearchBy<S>) -> Result<Option<Series>>
+    where
+        S: AsRef<str>,
+    {
+        let results = self.search(param).await?;
+
+        match results.first() {
+            Some(result) => self.series(result).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    async fn cached_languages(&self) -> Result<MutexGuard<'_, Option<Vec<Language>>>> {
+        let mut guard = self.languages.lock().await;
+
+        if guard.is_none() {
+            *guard = Some(self.languages().await?);
+        }
+
+        Ok(guard)
+    }
+
+    fn cache_genres(&self, new_genres: &[Genre]) {
+        let mut genres = self.genres.lock().expect("genre cache lock poisoned");
+
+        for genre in new_genres {
+            if !genres.iter().any(|g| g.id == genre.id) {
+                genres.push(genre.clone());
+            }
+        }
     }
 
-    /// Same as [`movie`], but allows deserializing the response movie data into
-    /// a provided type.
-    ///
-    /// [`movie`]: #method.movie
-    pub async fn movie_into<T, I>(&self, id: I) -> Result<T>
-    where
-        I: Into<MovieID>,
-        T: DeserializeOwned,
-    {
-        let res = self
-            .prep_lang_req(Method::GET, self.movies_url(id.into()))
-            .await?
-            .send()
+    async fn updated_chunked(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<SeriesUpdate>> {
+        let mut windows = Vec::new();
+        let mut window_from = from;
+
+        while window_from < to {
+            let window_to = (window_from + Duration::days(UPDATED_MAX_DAYS)).min(to);
+
+            windows.push(UpdatedParams::with_to_time(window_from, window_to));
+
+            window_from = window_to;
+        }
+
+        let chunks: Vec<Vec<SeriesUpdate>> = stream::iter(windows)
+            .map(|params| async move {
+                match self.updated(&params).await {
+                    Ok(chunk) => Ok(chunk),
+                    // the API responds with 404 when nothing was updated
+                    Err(Error::NotFound(Some(message)))
+                        if message.starts_with(NO_RESULTS_MESSAGE) =>
+                    {
+                        Ok(Vec::new())
+                    }
+                    Err(e) => Err(e),
+                }
+            })
+            .buffered(UPDATED_CONCURRENCY)
+            .try_collect()
             .await?;
 
-        api_errors(&res)?;
+        Ok(chunks.into_iter().flatten().collect())
+    }
 
-        Ok(res.json::<ResponseData<T>>().await?.data)
+    // all episodes of an aired season, sorted by aired episode number; empty
+    // if the season does not exist
+    async fn aired_season_episodes(
+        &self,
+        series_id: SeriesID,
+        season: u32,
+    ) -> Result<Vec<Episode>> {
+        let mut params = Some(EpisodeQueryParams::new(series_id).aired_season(season));
+        let mut episodes = Vec::new();
+
+        while let Some(p) = params {
+            let mut page = match self.series_episodes_query_opt(&p).await? {
+                Some(page) => page,
+                None => break,
+            };
+
+            params = page.next_page_query_params();
+
+            episodes.append(&mut page.episodes);
+        }
+
+        episodes.sort_by_key(|e| e.aired_episode_number);
+
+        Ok(episodes)
     }
 
-    /// Get a list of movies updated since the given time.
-    ///
-    /// Sends a `GET` request to the `/movieupdates` API endpoint.
-    ///
-    /// # Examples
-    /// ```no_run
-    /// # use thetvdb::{Client, error::Result};
-    /// #
-    /// # #[tokio::main]
-    /// # async fn main() -> Result<()> {
-    /// #
-    /// # let client = Client::new("KEY").await?;
-    /// #
-    /// use chrono::DateTime;
-    ///
-    /// let since = DateTime::parse_from_rfc3339("2020-01-20T00:00:00-00:00")?;
-    ///
-    /// let movie_updates = client.movie_updates(since).await?;
-    ///
-    /// println!("Movie IDs updated since 2020-01-20: {:?}", movie_updates.movies);
-    /// # Ok(())}
-    /// ```
-    pub async fn movie_updates<D>(&self, since: D) -> Result<MovieUpdates>
-    where
-        D: Into<DateTime<Utc>>,
-    {
-        self.movie_updates_into(since).await
+    async fn all_episodes(&self, id: SeriesID) -> Result<Vec<Episode>> {
+        self.all_episodes_in(id, None).await
     }
 
-    /// Same as [`movie_updates`], but allows deserializing the response data
-    /// into a provided type.
-    ///
-    /// [`movie_updates`]: #method.movie_updates
-    pub async fn movie_updates_into<T, D>(&self, since: D) -> Result<T>
+    // `abbr` sets the `Accept-Language` header, which is not sent otherwise
+    async fn all_episodes_in<T>(&self, id: SeriesID, abbr: Option<&str>) -> Result<Vec<T>>
     where
-        D: Into<DateTime<Utc>>,
         T: DeserializeOwned,
     {
-        let res = self
-            .prep_req(Method::GET, self.movie_updates_url())
-            .await?
-            .query(&[("since", since.into().timestamp())])
-            .send()
+        let first_page: EpisodePage<T> = self
+            .series_episodes_page(&EpisodeParams::new(id), abbr)
             .await?;
+        let next_page = first_page.current_page().saturating_add(1);
+        let last_page = first_page.last_page();
 
-        api_errors(&res)?;
+        let mut episodes = first_page.episodes;
 
-        Ok(res.json::<T>().await?)
+        let pages: Vec<EpisodePage<T>> = stream::iter(next_page..=last_page)
+            .map(|page| async move {
+                self.series_episodes_page(&EpisodeParams::with_page(id, page), abbr)
+                    .await
+            })
+            .buffered(EPISODE_PAGES_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        for mut page in pages {
+            episodes.append(&mut page.episodes);
+        }
+
+        Ok(episodes)
     }
 
-    fn create<S>(api_key: S) -> Self
+    async fn series_episodes_page<T>(
+        &self,
+        params: &EpisodeParams,
+        abbr: Option<&str>,
+    ) -> Result<EpisodePage<T>>
     where
-        S: Into<String>,
+        T: DeserializeOwned,
     {
-        Client {
-            base_url: Url::parse(BASE_URL).expect("could not parse BASE_URL"),
-            api_key: api_key.into(),
-            token: Mutex::new(None),
-            http_client: HttpClient::new(),
-            lang_abbr: "en".to_string(),
+        let url = self.series_episodes_url(params.series_id);
+
+        let req = match abbr {
+            Some(abbr) => self.prep_abbr_req(Method::GET, url, abbr).await?,
+            None => self.prep_req(Method::GET, url).await?,
         }
+        .query(&[("page", params.page)]);
+
+        let res = self.send(req).await?;
+
+        let res = api_errors(res).await?;
+
+        let mut page: EpisodePage<T> = res.json().await?;
+        page.series_id = params.series_id;
+        page.requested_page = Some(params.page);
+
+        Ok(page)
+    }
+
+    async fn episodes_step(
+        &self,
+        next: Option<EpisodeParams>,
+    ) -> Result<Option<(Vec<Episode>, Option<EpisodeParams>)>> {
+        let params = match next {
+            Some(p) => p,
+            None => return Ok(None),
+        };
+
+        let page = self.series_episodes(&params).await?;
+        let next = page.next_page_params();
+
+        Ok(Some((page.episodes, next)))
+    }
+
+    async fn reverse_episodes_step(
+        &self,
+        mut state: ReverseEpisodesState,
+    ) -> Result<Option<(Vec<Episode>, ReverseEpisodesState)>> {
+        let params = match state.next.take() {
+            Some(p) => p,
+            None => return Ok(None),
+        };
+
+        let page = if !state.started {
+            state.started = true;
+
+            let page = self.series_episodes(&params).await?;
+            let last_params = page.last_page_params();
+
+            if last_params == params {
+                page
+            } else {
+                state.start_page = Some(page);
+                self.series_episodes(&last_params).await?
+            }
+        } else {
+            match state.start_page.take() {
+                // avoid requesting the starting page twice
+                Some(p) if p.current_page() == params.page => p,
+                start_page => {
+                    state.start_page = start_page;
+                    self.series_episodes(&params).await?
+                }
+            }
+        };
+
+        state.next = page.prev_page_params();
+
+        let mut episodes = page.episodes;
+        episodes.reverse();
+
+        Ok(Some((episodes, state)))
+    }
+
+    fn decode_token(token: String) -> Result<TokenData> {
+        TokenRes { token }.try_into()
     }
 
     async fn login_set_token(&self) -> Result<()> {
@@ -1097,27 +4224,44 @@ impl Client {
     }
 
     async fn login(&self) -> Result<TokenData> {
-        let res = self
+        let req = self
             .http_client
             .post(self.login_url())
             .json(&AuthBody {
                 apikey: &self.api_key,
+                username: self.user.as_ref().map(|u| u.username.as_str()),
+                userkey: self.user.as_ref().map(|u| u.userkey.as_str()),
             })
-            .send()
-            .await?;
+            .header("Accept", self.accept.clone())
+            .headers(self.default_headers.clone());
 
-        api_errors(&res)?;
+        let res = self.send_with_retry(req).await?;
+
+        let res = api_errors(res).await?;
 
         let token_res: TokenRes = res.json().await?;
 
-        Ok(token_res.try_into()?)
+        token_res.try_into()
     }
 
     async fn ensure_valid_token(&self) -> Result<()> {
+        // the guard is held while logging in, so concurrent requests wait for
+        // a single login instead of each sending their own
         let mut guard = self.token.lock().await;
 
         match guard.as_ref() {
+            Some(_) if !self.manage_token => {}
             Some(t) if t.exp - Duration::seconds(TOKEN_EXP_LIMIT) >= Utc::now() => {}
+            // only tokens that have not expired yet can be refreshed
+            Some(t) if t.exp > Utc::now() => {
+                let token = match self.refresh(t).await {
+                    Ok(token) => token,
+                    Err(Error::InvalidAPIKey) => self.login().await?,
+                    Err(e) => return Err(e),
+                };
+
+                *guard = Some(token);
+            }
 
             _ => *guard = Some(self.login().await?),
         }
@@ -1125,6 +4269,23 @@ impl Client {
         Ok(())
     }
 
+    async fn refresh(&self, token: &TokenData) -> Result<TokenData> {
+        let req = self
+            .http_client
+            .get(self.refresh_token_url())
+            .header("Accept", self.accept.clone())
+            .headers(self.default_headers.clone())
+            .bearer_auth(&token.token);
+
+        let res = self.send_with_retry(req).await?;
+
+        let res = api_errors(res).await?;
+
+        let token_res: TokenRes = res.json().await?;
+
+        token_res.try_into()
+    }
+
     async fn set_token(&self, new_token: TokenData) -> Result<()> {
         let mut token = self.token.lock().await;
 
@@ -1133,12 +4294,174 @@ impl Client {
         Ok(())
     }
 
+    // sends an authenticated request, logging in again and replaying it once
+    // if the API rejects the token, which can be invalidated before it expires
+    async fn send(&self, req: RequestBuilder) -> Result<Response> {
+        let replay = if self.manage_token {
+            req.try_clone()
+        } else {
+            None
+        };
+
+        let res = self.send_with_retry(req).await?;
+
+        let replay = match replay {
+            Some(replay) if res.status() == StatusCode::UNAUTHORIZED => replay,
+            _ => return Ok(res),
+        };
+
+        let rejected = replay
+            .try_clone()
+            .and_then(|r| r.build().ok())
+            .and_then(|r| r.headers().get(AUTHORIZATION).cloned());
+
+        let bearer = {
+            let mut guard = self.token.lock().await;
+
+            let current = guard
+                .as_ref()
+                .map(|t| bearer_header(&t.token))
+                .transpose()?;
+
+            // another request may have logged in again already
+            match current {
+                Some(current) if Some(&current) != rejected.as_ref() => current,
+                _ => {
+                    log::debug!("token rejected by the API, logging in again");
+
+                    let token = self.login().await?;
+                    let bearer = bearer_header(&token.token)?;
+
+                    *guard = Some(token);
+
+                    bearer
+                }
+            }
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, bearer);
+
+        self.send_with_retry(replay.headers(headers)).await
+    }
+
+    async fn send_with_retry(&self, mut req: RequestBuilder) -> Result<Response> {
+        let mut attempt = 0;
+
+        loop {
+            let retry = match &self.retry {
+                Some(config) if attempt < config.max_retries() => {
+                    req.try_clone().map(|r| (config, r))
+                }
+                _ => None,
+            };
+
+            let res = self.send_once(req).await;
+
+            let (config, retry_req) = match retry {
+                Some(retry) if is_transient(&res) => retry,
+                _ => return res,
+            };
+
+            attempt += 1;
+
+            let delay = config.delay(attempt);
+
+            // only 5XX responses are retried
+            let error = res.err().unwrap_or(Error::ServerError);
+
+            log::debug!(
+                "retrying request (attempt {}) in {:?}: {}",
+                attempt,
+                delay,
+                error
+            );
+
+            if let Some(on_retry) = &self.on_retry {
+                on_retry.call(attempt, &error, delay);
+            }
+
+            tokio::time::sleep(delay).await;
+
+            req = retry_req;
+        }
+    }
+
+    async fn download_poster(&self, id: SeriesID) -> Result<Bytes> {
+        match self.series_poster(id).await? {
+            Some(url) => self.fetch_image(&url).await,
+            None => Err(Error::MissingImage),
+        }
+    }
+
+    async fn send_once(&self, req: RequestBuilder) -> Result<Response> {
+        let _permit = match &self.request_semaphore {
+            Some(semaphore) => Some(semaphore.acquire().await.expect("request semaphore closed")),
+            None => None,
+        };
+
+        let req = match self.timeout {
+            Some(timeout) => req.timeout(timeout),
+            None => req,
+        };
+
+        Ok(req.send().await?)
+    }
+
+    async fn data_with_id<T>(&self, res: Response, requested: u64) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        if !self.verify_ids {
+            return Ok(res.json::<ResponseData<T>>().await?.data);
+        }
+
+        let body = res.bytes().await?;
+
+        let data = decode_body::<ResponseData<serde_json::Value>>(body.clone())
+            .await?
+            .data;
+
+        match data.get("id").and_then(serde_json::Value::as_u64) {
+            Some(returned) if returned != requested => Err(Error::IdMismatch {
+                requested,
+                returned,
+            }),
+            _ => Ok(decode_body::<ResponseData<T>>(body).await?.data),
+        }
+    }
+
     async fn prep_req(&self, method: Method, url: Url) -> Result<RequestBuilder> {
+        let abbr = if self.always_send_language {
+            Some(self.lang_abbr.as_str())
+        } else {
+            None
+        };
+
+        self.prep_opt_abbr_req(method, url, abbr).await
+    }
+
+    async fn prep_lang_req(&self, method: Method, url: Url) -> Result<RequestBuilder> {
+        self.prep_abbr_req(method, url, &self.lang_abbr).await
+    }
+
+    async fn prep_abbr_req(&self, method: Method, url: Url, abbr: &str) -> Result<RequestBuilder> {
+        self.prep_opt_abbr_req(method, url, Some(abbr)).await
+    }
+
+    async fn prep_opt_abbr_req(
+        &self,
+        method: Method,
+        url: Url,
+        abbr: Option<&str>,
+    ) -> Result<RequestBuilder> {
         self.ensure_valid_token().await?;
-        let req = self
+        let mut req = self
             .http_client
             .request(method, url)
             .header("Content-Type", "application/json")
+            .header("Accept", self.accept.clone())
+            .headers(self.default_headers.clone())
             .bearer_auth(
                 &self
                     .token
@@ -1149,130 +4472,345 @@ impl Client {
                     .token,
             );
 
-        Ok(req)
-    }
+        if let Some(abbr) = abbr {
+            req = req.header("Accept-Language", abbr);
+        }
 
-    async fn prep_lang_req(&self, method: Method, url: Url) -> Result<RequestBuilder> {
-        self.prep_req(method, url)
-            .await
-            .map(|r| r.header("Accept-Language", &self.lang_abbr))
+        Ok(req)
     }
 
     fn login_url(&self) -> Url {
         self.base_url
-            .join("/login")
+            .join("login")
             .expect("could not parse login url")
     }
 
+    fn refresh_token_url(&self) -> Url {
+        self.base_url
+            .join("refresh_token")
+            .expect("could not parse refresh token url")
+    }
+
     fn search_url(&self) -> Url {
         self.base_url
-            .join("/search/series")
+            .join("search/series")
             .expect("could not parse search url")
     }
 
     fn series_url(&self, id: SeriesID) -> Url {
         self.base_url
-            .join(&format!("/series/{}", id))
+            .join(&format!("series/{}", id))
             .expect("could not parse series url")
     }
 
     fn series_actors_url(&self, id: SeriesID) -> Url {
         self.base_url
-            .join(&format!("/series/{}/actors", id))
+            .join(&format!("series/{}/actors", id))
             .expect("could not parse actors url")
     }
 
     fn series_episodes_url(&self, id: SeriesID) -> Url {
         self.base_url
-            .join(&format!("/series/{}/episodes", id))
+            .join(&format!("series/{}/episodes", id))
             .expect("could not parse episodes url")
     }
 
     fn series_episodes_query_url(&self, id: SeriesID) -> Url {
         self.base_url
-            .join(&format!("/series/{}/episodes/query", id))
+            .join(&format!("series/{}/episodes/query", id))
             .expect("could not parse episodes query url")
     }
 
+    fn series_episodes_query_params_url(&self, id: SeriesID) -> Url {
+        self.base_url
+            .join(&format!("series/{}/episodes/query/params", id))
+            .expect("could not parse episodes query params url")
+    }
+
     fn series_episodes_summary_url(&self, id: SeriesID) -> Url {
         self.base_url
-            .join(&format!("/series/{}/episodes/summary", id))
+            .join(&format!("series/{}/episodes/summary", id))
             .expect("could not parse episodes summary url")
     }
 
     fn series_filter_url(&self, id: SeriesID) -> Url {
         self.base_url
-            .join(&format!("/series/{}/filter", id))
+            .join(&format!("series/{}/filter", id))
             .expect("could not parse series filter url")
     }
 
     fn series_images_url(&self, id: SeriesID) -> Url {
         self.base_url
-            .join(&format!("/series/{}/images", id))
+            .join(&format!("series/{}/images", id))
             .expect("could not parse series images url")
     }
 
     fn series_images_query_url(&self, id: SeriesID) -> Url {
         self.base_url
-            .join(&format!("/series/{}/images/query", id))
+            .join(&format!("series/{}/images/query", id))
             .expect("could not parse series images query url")
     }
 
     fn series_images_query_params_url(&self, id: SeriesID) -> Url {
         self.base_url
-            .join(&format!("/series/{}/images/query/params", id))
+            .join(&format!("series/{}/images/query/params", id))
             .expect("could not parse series images query params url")
     }
 
     fn episodes_url(&self, id: EpisodeID) -> Url {
         self.base_url
-            .join(&format!("/episodes/{}", id))
+            .join(&format!("episodes/{}", id))
             .expect("could not parse episodes url")
     }
 
     fn languages_url(&self) -> Url {
         self.base_url
-            .join("/languages")
+            .join("languages")
             .expect("could not parse languages url")
     }
 
     fn language_url(&self, id: LanguageID) -> Url {
         self.base_url
-            .join(&format!("/languages/{}", id))
+            .join(&format!("languages/{}", id))
             .expect("could not parse language url")
     }
 
     fn updated_url(&self) -> Url {
         self.base_url
-            .join("/updated/query")
+            .join("updated/query")
             .expect("could not parse updated url")
     }
 
     fn movies_url(&self, id: MovieID) -> Url {
         self.base_url
-            .join(&format!("/movies/{}", id))
+            .join(&format!("movies/{}", id))
             .expect("could not parse movie url")
     }
 
     fn movie_updates_url(&self) -> Url {
         self.base_url
-            .join("/movieupdates")
+            .join("movieupdates")
             .expect("could not parse movie updates url")
     }
+
+    fn user_favorites_url(&self) -> Url {
+        self.base_url
+            .join("user/favorites")
+            .expect("could not parse user favorites url")
+    }
+
+    fn user_ratings_url(&self) -> Url {
+        self.base_url
+            .join("user/ratings")
+            .expect("could not parse user ratings url")
+    }
+
+    fn user_rating_url(&self, item_type: RatingItemType, id: u32, rating: Option<u8>) -> Url {
+        let path = match rating {
+            Some(rating) => format!("user/ratings/{}/{}/{}", item_type.as_str(), id, rating),
+            None => format!("user/ratings/{}/{}", item_type.as_str(), id),
+        };
+
+        self.base_url
+            .join(&path)
+            .expect("could not parse user rating url")
+    }
 }
 
-fn api_errors(res: &Response) -> Result<()> {
+struct ReverseEpisodesState {
+    next: Option<EpisodeParams>,
+    started: bool,
+    start_page: Option<EpisodePage>,
+}
+
+/// Authentication token data returned by [`Client::export_token`].
+///
+/// See linked method for more info.
+///
+/// [`Client::export_token`]: struct.Client.html#method.export_token
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TokenSnapshot {
+    /// The raw JWT.
+    pub token: String,
+    /// Date and time when the token was issued.
+    pub created: DateTime<Utc>,
+    /// Date and time when the token expires.
+    pub exp: DateTime<Utc>,
+}
+
+async fn api_errors(res: Response) -> Result<Response> {
     match res.status().into() {
         401 => Err(Error::InvalidAPIKey),
-        404 => Err(Error::NotFound),
+        404 => Err(Error::NotFound(error_message(res).await)),
+        429 => Err(Error::RateLimited {
+            retry_after: retry_after(&res),
+        }),
         500..=599 => Err(Error::ServerError),
-        _ => Ok(()),
+        _ => Ok(res),
+    }
+}
+
+// the message of error responses like `{"Error": "ID not found"}`
+async fn error_message(res: Response) -> Option<String> {
+    #[derive(Deserialize)]
+    struct ErrorBody {
+        #[serde(rename = "Error")]
+        error: String,
+    }
+
+    res.json::<ErrorBody>().await.ok().map(|b| b.error)
+}
+
+// decodes an already read response body like `Response::json` does, so
+// failures are reported as `Error::Decode` in both cases
+async fn decode_body<T>(body: Bytes) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    Ok(Response::from(http::Response::new(body)).json().await?)
+}
+
+fn bearer_header(token: &str) -> Result<HeaderValue> {
+    let mut value = HeaderValue::from_str(&format!("Bearer {}", token))?;
+    value.set_sensitive(true);
+
+    Ok(value)
+}
+
+fn accept_header(api_version: &str) -> Result<HeaderValue> {
+    Ok(HeaderValue::from_str(&format!(
+        "application/vnd.thetvdb.v{}",
+        api_version
+    ))?)
+}
+
+// sorts regular episodes by season and number, inserting specials according
+// to their placement data or, if missing, their air date
+fn watch_order(episodes: Vec<Episode>) -> Vec<Episode> {
+    // (season, slot, special number): regular episodes use odd slots so
+    // specials airing before them can use the even slot right below
+    type Key = (u32, u64, u32);
+
+    let regular_key = |e: &Episode| -> Key {
+        (
+            e.aired_season.unwrap_or(u32::MAX),
+            u64::from(e.aired_episode_number) * 2 + 1,
+            0,
+        )
+    };
+
+    let (specials, regular): (Vec<Episode>, Vec<Episode>) = episodes
+        .into_iter()
+        .partition(|e| e.aired_season == Some(0));
+
+    let mut keyed: Vec<(Key, Episode)> =
+        regular.into_iter().map(|e| (regular_key(&e), e)).collect();
+
+    keyed.sort_by_key(|(key, _)| *key);
+
+    let special_keys: Vec<Key> = specials
+        .iter()
+        .map(|s| {
+            let number = s.aired_episode_number;
+
+            match (
+                s.airs_before_season,
+                s.airs_before_episode,
+                s.airs_after_season,
+            ) {
+                (Some(season), Some(episode), _) => (season, u64::from(episode) * 2, number),
+                (Some(season), None, _) => (season, 0, number),
+                (None, _, Some(season)) => (season, u64::MAX, number),
+                (None, _, None) => {
+                    let next_aired = s.first_aired.and_then(|aired| {
+                        keyed
+                            .iter()
+                            .find(|(_, e)| e.first_aired.filter(|d| *d > aired).is_some())
+                    });
+
+                    match next_aired {
+                        Some(((season, slot, _), _)) => (*season, slot - 1, number),
+                        None => (u32::MAX, u64::MAX, number),
+                    }
+                }
+            }
+        })
+        .collect();
+
+    keyed.extend(special_keys.into_iter().zip(specials));
+
+    // stable, so regular episodes stay in order
+    keyed.sort_by_key(|(key, _)| *key);
+
+    keyed.into_iter().map(|(_, e)| e).collect()
+}
+
+fn is_transient(res: &Result<Response>) -> bool {
+    match res {
+        Ok(res) => res.status().is_server_error(),
+        Err(Error::Http(e)) => e.is_connect() || e.is_timeout(),
+        Err(_) => false,
+    }
+}
+
+// `Retry-After` can be a number of seconds or an HTTP date
+fn retry_after(res: &Response) -> Option<std::time::Duration> {
+    let value = res.headers().get("Retry-After")?.to_str().ok()?.trim();
+
+    if let Ok(secs) = value.parse() {
+        return Some(std::time::Duration::from_secs(secs));
     }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+fn last_modified(res: &Response) -> Result<DateTime<Utc>> {
+    let lm_header = res
+        .headers()
+        .get("Last-Modified")
+        .ok_or(Error::MissingLastModified)
+        .map(HeaderValue::to_str)??;
+
+    Ok(DateTime::parse_from_rfc2822(lm_header)?.into())
 }
 
 #[derive(Debug, Serialize)]
 struct AuthBody<'a> {
     apikey: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    userkey: Option<&'a str>,
+}
+
+// user account credentials sent on login, see `Client::new_user`
+#[derive(Clone)]
+struct UserAuth {
+    username: String,
+    userkey: String,
+}
+
+impl fmt::Debug for UserAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserAuth")
+            .field("username", &self.username)
+            .field("userkey", &"<redacted>")
+            .finish()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct UserFavorites {
+    // the API sends the ids as strings, and `[""]` if there are none
+    #[serde(deserialize_with = "crate::serialization::string_or_int_list")]
+    favorites: Vec<SeriesID>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1295,6 +4833,18 @@ struct TokenData {
     exp: DateTime<Utc>,
 }
 
+impl TokenData {
+    fn opaque(token: String) -> Self {
+        let created = Utc::now();
+
+        Self {
+            token,
+            created,
+            exp: created + Duration::seconds(OPAQUE_TOKEN_LIFETIME),
+        }
+    }
+}
+
 impl fmt::Display for TokenData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self)
@@ -1305,15 +4855,32 @@ impl TryFrom<TokenRes> for TokenData {
     type Error = Error;
 
     fn try_from(res: TokenRes) -> Result<TokenData> {
+        if let Err(e) = jsonwebtoken::decode_header(&res.token) {
+            log::warn!(
+                "could not decode authentication token as JWT ({}), \
+                 assuming it expires in {} hours",
+                e,
+                OPAQUE_TOKEN_LIFETIME / 3600
+            );
+
+            return Ok(TokenData::opaque(res.token));
+        }
+
         // TheTVDB API JWT public key is not available,
         // thus the use of `dangerous_unsafe_decode`
         let payload = jsonwebtoken::dangerous_insecure_decode::<TokenPayload>(&res.token)?.claims;
 
-        Ok(TokenData {
+        let token_data = TokenData {
             token: res.token,
             created: payload.orig_iat,
             exp: payload.exp,
-        })
+        };
+
+        if token_data.exp <= token_data.created {
+            return Err(Error::InvalidTokenPayload);
+        }
+
+        Ok(token_data)
     }
 }
 
@@ -0,0 +1,549 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::lock::Mutex;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Client as HttpClient,
+};
+use tokio::sync::Semaphore;
+use url::Url;
+
+use super::{accept_header, Client, RetryConfig, RetryHook, UserAuth, BASE_URL};
+use crate::error::{Error, Result};
+use crate::language::Language;
+
+/// Builder used to configure and create a [`Client`].
+///
+/// Created with [`Client::builder`].
+///
+/// # Examples
+/// ```no_run
+/// # use thetvdb::{Client, error::Result};
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// #
+/// let client = Client::builder("KEY")
+///     .preload_languages(true)
+///     .build()
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`Client`]: struct.Client.html
+/// [`Client::builder`]: struct.Client.html#method.builder
+#[derive(Debug)]
+pub struct ClientBuilder {
+    pub(super) base_url: Url,
+    pub(super) api_key: String,
+    pub(super) preload_languages: bool,
+    pub(super) default_headers: HeaderMap,
+    pub(super) config_error: Option<Error>,
+    pub(super) max_concurrent_requests: Option<usize>,
+    pub(super) verify_ids: bool,
+    pub(super) always_send_language: bool,
+    pub(super) retry: Option<RetryConfig>,
+    pub(super) on_retry: Option<RetryHook>,
+    pub(super) token: Option<String>,
+    pub(super) manage_token: bool,
+    pub(super) api_version: Option<String>,
+    pub(super) lang_abbr: Option<String>,
+    pub(super) timeout: Option<Duration>,
+    pub(super) http_client: Option<HttpClient>,
+    pub(super) user: Option<UserAuth>,
+}
+
+impl ClientBuilder {
+    pub(super) fn new(api_key: String) -> Self {
+        Self {
+            base_url: Url::parse(BASE_URL).expect("could not parse BASE_URL"),
+            api_key,
+            preload_languages: false,
+            default_headers: HeaderMap::new(),
+            config_error: None,
+            max_concurrent_requests: None,
+            verify_ids: false,
+            always_send_language: false,
+            retry: None,
+            on_retry: None,
+            token: None,
+            manage_token: true,
+            api_version: None,
+            lang_abbr: None,
+            timeout: None,
+            http_client: None,
+            user: None,
+        }
+    }
+
+    /// Set the api key used to authenticate.
+    ///
+    /// Replaces the one given to [`Client::builder`].
+    ///
+    /// # Errors
+    /// If the api key is empty, [`build`] will return
+    /// [`Error::MissingAPIKey`].
+    ///
+    /// [`Client::builder`]: struct.Client.html#method.builder
+    /// [`build`]: #method.build
+    /// [`Error::MissingAPIKey`]: ../error/enum.Error.html#variant.MissingAPIKey
+    pub fn api_key<S>(mut self, api_key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.api_key = api_key.into();
+        self
+    }
+
+    /// Authenticate a user account along with the api key.
+    ///
+    /// Works like [`Client::new_user`].
+    ///
+    /// [`Client::new_user`]: struct.Client.html#method.new_user
+    pub fn user<U, K>(mut self, username: U, userkey: K) -> Self
+    where
+        U: Into<String>,
+        K: Into<String>,
+    {
+        self.user = Some(UserAuth {
+            username: username.into(),
+            userkey: userkey.into(),
+        });
+        self
+    }
+
+    /// Set the language used by the client.
+    ///
+    /// Works like [`Client::set_language`].
+    ///
+    /// [`Client::set_language`]: struct.Client.html#method.set_language
+    pub fn language(self, language: &Language) -> Self {
+        self.language_abbr(language.abbreviation.as_str())
+    }
+
+    /// Set the abbreviation of the language used by the client.
+    ///
+    /// Works like [`Client::set_language_abbr`]. Defaults to `en`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::builder("KEY").language_abbr("ko").build().await?;
+    ///
+    /// let planet_earth_ii_ko = client.series(318408).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Client::set_language_abbr`]: struct.Client.html#method.set_language_abbr
+    pub fn language_abbr<S>(mut self, abbr: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.lang_abbr = Some(abbr.into());
+        self
+    }
+
+    /// Set a timeout for each request sent by the client, from when it starts
+    /// connecting until the response body has been received.
+    ///
+    /// Each retry (see [`retry`]) gets its own timeout. Requests that time
+    /// out fail with [`Error::Http`].
+    ///
+    /// Overrides the timeout of the [`http_client`], if any. By default there
+    /// is no timeout.
+    ///
+    /// [`retry`]: #method.retry
+    /// [`http_client`]: #method.http_client
+    /// [`Error::Http`]: ../error/enum.Error.html#variant.Http
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests using the given [`reqwest::Client`].
+    ///
+    /// Works like [`Client::with_http_client`].
+    ///
+    /// [`reqwest::Client`]: https://docs.rs/reqwest/latest/reqwest/struct.Client.html
+    /// [`Client::with_http_client`]: struct.Client.html#method.with_http_client
+    pub fn http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Set the base URL of the API.
+    ///
+    /// Useful to send requests through a proxy or mirror of the API, or to a
+    /// mock server in tests. A trailing `/` is added to the URL if missing,
+    /// so paths like `https://proxy.example.com/thetvdb` are kept.
+    ///
+    /// Defaults to `https://api.thetvdb.com/`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::builder("KEY")
+    ///     .base_url("http://localhost:8080/thetvdb/")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// If the URL cannot be parsed, [`build`] will return
+    /// [`Error::InvalidUrl`].
+    ///
+    /// [`build`]: #method.build
+    /// [`Error::InvalidUrl`]: ../error/enum.Error.html#variant.InvalidUrl
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        if self.config_error.is_some() {
+            return self;
+        }
+
+        match Url::parse(base_url) {
+            Ok(mut url) => {
+                if !url.path().ends_with('/') {
+                    let path = format!("{}/", url.path());
+                    url.set_path(&path);
+                }
+
+                self.base_url = url;
+            }
+            Err(e) => self.config_error = Some(e.into()),
+        }
+
+        self
+    }
+
+    /// Fetch the list of available languages when building the client.
+    ///
+    /// The languages are cached, so methods like
+    /// [`Client::language_by_abbr`] will not need to send a request.
+    ///
+    /// If the languages cannot be fetched, the client is still built and the
+    /// languages will be requested when first needed.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`Client::language_by_abbr`]: struct.Client.html#method.language_by_abbr
+    pub fn preload_languages(mut self, preload: bool) -> Self {
+        self.preload_languages = preload;
+        self
+    }
+
+    /// Add a header to be sent with every request.
+    ///
+    /// Headers set this way replace the ones set by the client with the same
+    /// name, including `Content-Type`. Adding a header with the same name
+    /// more than once keeps only the last value.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::builder("KEY")
+    ///     .default_header("X-Request-ID", "f5b2c1d8")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// If the header name or value is not valid, [`build`] will return an
+    /// error.
+    ///
+    /// [`build`]: #method.build
+    pub fn default_header(mut self, name: &str, value: &str) -> Self {
+        if self.config_error.is_some() {
+            return self;
+        }
+
+        let header = HeaderName::from_bytes(name.as_bytes())
+            .map_err(Error::from)
+            .and_then(|n| Ok((n, HeaderValue::from_str(value)?)));
+
+        match header {
+            Ok((name, value)) => {
+                self.default_headers.insert(name, value);
+            }
+            Err(e) => self.config_error = Some(e),
+        }
+
+        self
+    }
+
+    /// Set the API version requested with the `Accept` header.
+    ///
+    /// The header is sent as `application/vnd.thetvdb.v{api_version}`, so
+    /// the API responds with the given version even if its default version
+    /// changes. Defaults to `3.0.0`.
+    ///
+    /// The response types of this crate model version `3` of the API, which
+    /// is the only supported version. Other versions can be requested, e.g.
+    /// to be used with the `_into` methods.
+    ///
+    /// # Errors
+    /// If the version is not valid in a header value, [`build`] will return
+    /// an error.
+    ///
+    /// [`build`]: #method.build
+    pub fn api_version(mut self, api_version: &str) -> Self {
+        self.api_version = Some(api_version.to_string());
+        self
+    }
+
+    /// Limit the number of requests the client sends at the same time.
+    ///
+    /// Requests over the limit wait until one of the requests in flight
+    /// receives its response.
+    ///
+    /// By default there is no limit.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::builder("KEY")
+    ///     .max_concurrent_requests(4)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    /// If `max` is `0`, [`build`] will return
+    /// [`Error::InvalidConcurrencyLimit`].
+    ///
+    /// [`build`]: #method.build
+    /// [`Error::InvalidConcurrencyLimit`]: ../error/enum.Error.html#variant.InvalidConcurrencyLimit
+    pub fn max_concurrent_requests(mut self, max: usize) -> Self {
+        if self.config_error.is_some() {
+            return self;
+        }
+
+        if max == 0 {
+            self.config_error = Some(Error::InvalidConcurrencyLimit);
+        } else {
+            self.max_concurrent_requests = Some(max);
+        }
+
+        self
+    }
+
+    /// Check that the data returned by requests for a single series, episode,
+    /// movie or language has the requested id.
+    ///
+    /// When enabled, methods like [`Client::series_into`] return
+    /// [`Error::IdMismatch`] if the `id` in the response differs from the
+    /// requested one, even if the provided type has no `id` field. Responses
+    /// without an `id` are not checked.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`Client::series_into`]: struct.Client.html#method.series_into
+    /// [`Error::IdMismatch`]: ../error/enum.Error.html#variant.IdMismatch
+    pub fn verify_ids(mut self, verify: bool) -> Self {
+        self.verify_ids = verify;
+        self
+    }
+
+    /// Send the client's language as the `Accept-Language` header with every
+    /// authenticated request.
+    ///
+    /// By default the header is only sent to the endpoints that TheTVDB API
+    /// documents as localized:
+    /// * `/search/series`
+    /// * `/series/{id}`
+    /// * `/series/{id}/episodes/query`
+    /// * `/series/{id}/filter`
+    /// * `/series/{id}/images`, `/series/{id}/images/query` and
+    ///   `/series/{id}/images/query/params`
+    /// * `/episodes/{id}`
+    /// * `/updated/query`
+    /// * `/movies/{id}`
+    ///
+    /// When enabled, it is also sent to all other endpoints, e.g.
+    /// `/series/{id}/actors` and `/series/{id}/episodes`, in case the API
+    /// localizes their data too. Methods taking a language abbreviation, like
+    /// [`Client::series_actors_in_language`], still send the given one.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`Client::series_actors_in_language`]: struct.Client.html#method.series_actors_in_language
+    pub fn always_send_language(mut self, always: bool) -> Self {
+        self.always_send_language = always;
+        self
+    }
+
+    /// Retry requests that fail because of transient errors.
+    ///
+    /// See [`RetryConfig`] for more info.
+    ///
+    /// By default requests are not retried.
+    ///
+    /// [`RetryConfig`]: struct.RetryConfig.html
+    pub fn retry(mut self, config: RetryConfig) -> Self {
+        self.retry = Some(config);
+        self
+    }
+
+    /// Call a function before each retry.
+    ///
+    /// The function receives the retry attempt number, starting from `1`,
+    /// the error that caused the retry and the delay that will be waited
+    /// before sending the request again. Requests failing with a `5XX`
+    /// response are reported with [`Error::ServerError`].
+    ///
+    /// Has no effect if retries are not enabled with [`retry`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// use thetvdb::client::RetryConfig;
+    ///
+    /// let client = Client::builder("KEY")
+    ///     .retry(RetryConfig::new(3))
+    ///     .on_retry(|attempt, error, delay| {
+    ///         eprintln!("retry {} in {:?} after error: {}", attempt, delay, error);
+    ///     })
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Error::ServerError`]: ../error/enum.Error.html#variant.ServerError
+    /// [`retry`]: #method.retry
+    pub fn on_retry<F>(mut self, f: F) -> Self
+    where
+        F: Fn(u32, &Error, Duration) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(RetryHook::new(f));
+        self
+    }
+
+    /// Use a previously obtained authentication token instead of logging in
+    /// when building the client.
+    ///
+    /// Works like [`Client::with_token`].
+    ///
+    /// [`Client::with_token`]: struct.Client.html#method.with_token
+    pub fn token<S>(mut self, token: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Enable or disable automatic token management.
+    ///
+    /// When disabled, the client never logs in again to refresh its token,
+    /// even after it expires, and keeps using the token set with [`token`]
+    /// (or obtained when building the client, if none was set).
+    ///
+    /// **Intended for tests only**, e.g. to make requests sent to a mock
+    /// server predictable. Real clients should keep it enabled.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// let client = Client::builder("KEY")
+    ///     .token("TEST_TOKEN")
+    ///     .manage_token(false)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`token`]: #method.token
+    pub fn manage_token(mut self, manage: bool) -> Self {
+        self.manage_token = manage;
+        self
+    }
+
+    /// Create the client and authenticate using its api key, unless a token
+    /// was provided with [`token`].
+    ///
+    /// # Errors
+    /// Will fail if the api key is empty or not valid, if the provided token
+    /// cannot be decoded or if an invalid base URL, default header or API
+    /// version was set.
+    ///
+    /// [`token`]: #method.token
+    pub async fn build(self) -> Result<Client> {
+        if let Some(e) = self.config_error {
+            return Err(e);
+        }
+
+        if self.api_key.is_empty() {
+            return Err(Error::MissingAPIKey);
+        }
+
+        let accept = self.api_version.as_deref().map(accept_header).transpose()?;
+
+        let token = self.token.map(Client::decode_token).transpose()?;
+        let has_token = token.is_some();
+
+        let mut client = Client {
+            base_url: self.base_url,
+            default_headers: self.default_headers,
+            request_semaphore: self
+                .max_concurrent_requests
+                .map(|max| Arc::new(Semaphore::new(max))),
+            verify_ids: self.verify_ids,
+            always_send_language: self.always_send_language,
+            retry: self.retry,
+            on_retry: self.on_retry,
+            manage_token: self.manage_token,
+            token: Arc::new(Mutex::new(token)),
+            timeout: self.timeout,
+            user: self.user,
+            ..Client::create_with_http_client(self.api_key, self.http_client.unwrap_or_default())
+        };
+
+        if let Some(accept) = accept {
+            client.accept = accept;
+        }
+
+        if let Some(abbr) = self.lang_abbr {
+            client.lang_abbr = abbr;
+        }
+
+        if !has_token {
+            client.login_set_token().await?;
+        }
+
+        if self.preload_languages {
+            if let Err(e) = client.cached_languages().await {
+                log::warn!("could not preload languages: {}", e);
+            }
+        }
+
+        Ok(client)
+    }
+}
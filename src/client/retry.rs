@@ -0,0 +1,170 @@
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::error::Error;
+
+/// Configuration used by the client to retry requests that failed because of
+/// transient errors.
+///
+/// Requests are retried when they cannot connect to the API, when they time
+/// out or when the API returns a `5XX` response.
+///
+/// Set with [`ClientBuilder::retry`].
+///
+/// # Examples
+/// ```no_run
+/// # use thetvdb::{Client, error::Result};
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// #
+/// use std::time::Duration;
+/// use thetvdb::client::{RetryConfig, RetryStrategy};
+///
+/// let retry = RetryConfig::new(3)
+///     .strategy(RetryStrategy::Fixed(Duration::from_secs(2)))
+///     .jitter(true);
+///
+/// let client = Client::builder("KEY").retry(retry).build().await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`ClientBuilder::retry`]: struct.ClientBuilder.html#method.retry
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryConfig {
+    max_retries: u32,
+    strategy: RetryStrategy,
+    jitter: bool,
+}
+
+impl RetryConfig {
+    /// Create a new retry configuration that retries a request at most
+    /// `max_retries` times.
+    ///
+    /// Uses [`RetryStrategy::default`] and no jitter.
+    ///
+    /// [`RetryStrategy::default`]: enum.RetryStrategy.html#impl-Default
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            strategy: RetryStrategy::default(),
+            jitter: false,
+        }
+    }
+
+    /// Set the strategy used to compute the delay before each retry.
+    pub fn strategy(mut self, strategy: RetryStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Wait a random time between zero and the delay computed by the
+    /// strategy ("full jitter").
+    ///
+    /// Spreads out the retries of clients that failed at the same time.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Returns the maximum number of times a request is retried.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the delay to wait before the given retry attempt, starting
+    /// from `1`.
+    ///
+    /// With jitter enabled the returned delay is random and will differ
+    /// between calls.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let delay = self.strategy.delay(attempt);
+
+        if self.jitter {
+            delay.mul_f64(random_fraction())
+        } else {
+            delay
+        }
+    }
+}
+
+/// Strategy used to compute the delay between retries.
+///
+/// See [`RetryConfig`] for more info.
+///
+/// [`RetryConfig`]: struct.RetryConfig.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RetryStrategy {
+    /// Double the delay after each retry, starting from `base` and never
+    /// waiting longer than `max`.
+    Exponential {
+        /// Delay before the first retry.
+        base: Duration,
+        /// Maximum delay.
+        max: Duration,
+    },
+    /// Wait the same time before every retry.
+    Fixed(Duration),
+    /// Retry immediately.
+    None,
+}
+
+impl RetryStrategy {
+    fn delay(&self, attempt: u32) -> Duration {
+        match self {
+            RetryStrategy::Exponential { base, max } => {
+                let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+
+                base.checked_mul(factor)
+                    .map_or(*max, |delay| delay.min(*max))
+            }
+            RetryStrategy::Fixed(delay) => *delay,
+            RetryStrategy::None => Duration::from_secs(0),
+        }
+    }
+}
+
+impl Default for RetryStrategy {
+    /// Exponential backoff starting from 500 milliseconds, up to 30 seconds.
+    fn default() -> Self {
+        RetryStrategy::Exponential {
+            base: Duration::from_millis(500),
+            max: Duration::from_secs(30),
+        }
+    }
+}
+
+type RetryFn = dyn Fn(u32, &Error, Duration) + Send + Sync;
+
+// function called before each retry, see `ClientBuilder::on_retry`
+#[derive(Clone)]
+pub(super) struct RetryHook(Arc<RetryFn>);
+
+impl RetryHook {
+    pub(super) fn new<F>(f: F) -> Self
+    where
+        F: Fn(u32, &Error, Duration) + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    pub(super) fn call(&self, attempt: u32, error: &Error, delay: Duration) {
+        (self.0)(attempt, error, delay)
+    }
+}
+
+impl fmt::Debug for RetryHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RetryHook")
+    }
+}
+
+// random number in `[0, 1]`, good enough for jitter
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+
+    random as f64 / u64::MAX as f64
+}
@@ -1,5 +1,5 @@
-use chrono::{Duration, Utc};
-use futures::executor::block_on;
+use chrono::{Duration, NaiveDate, Utc};
+use futures::{executor::block_on, TryStreamExt};
 use jsonwebtoken as jwt;
 use mockito::{
     mock,
@@ -18,6 +18,7 @@ const GET: &str = "GET";
 const HEAD: &str = "HEAD";
 
 const LOGIN_PATH: &str = "/login";
+const REFRESH_TOKEN_PATH: &str = "/refresh_token";
 const SEARCH_PATH: &str = "/search/series";
 
 const SERIES_ID: u32 = 32167;
@@ -61,11 +62,13 @@ fn urls_must_parse() {
     let client = Client::create("key");
 
     client.login_url();
+    client.refresh_token_url();
     client.search_url();
     client.series_url(SeriesID(1));
     client.series_actors_url(SeriesID(1));
     client.series_episodes_url(SeriesID(1));
     client.series_episodes_query_url(SeriesID(1));
+    client.series_episodes_query_params_url(SeriesID(1));
     client.series_episodes_summary_url(SeriesID(1));
     client.series_filter_url(SeriesID(1));
     client.series_images_url(SeriesID(1));
@@ -77,6 +80,10 @@ fn urls_must_parse() {
     client.updated_url();
     client.movies_url(MovieID(1));
     client.movie_updates_url();
+    client.user_favorites_url();
+    client.user_ratings_url();
+    client.user_rating_url(RatingItemType::Series, 1, Some(10));
+    client.user_rating_url(RatingItemType::Image, 1, None);
 }
 
 #[tokio::test]
@@ -114,386 +121,3889 @@ async fn client_login() -> Result<()> {
 }
 
 #[tokio::test]
-async fn client_relogin_on_token_exp() -> Result<()> {
+async fn client_login_user() -> Result<()> {
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let req_body = json!({
+        "apikey": API_KEY,
+        "username": "test_user",
+        "userkey": "TEST_USER_KEY",
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .match_body(Matcher::Json(req_body))
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .create();
+
+    let client = Client::builder(API_KEY)
+        .base_url(&mockito::server_url())
+        .user("test_user", "TEST_USER_KEY")
+        .build()
+        .await?;
+
+    login_mock.assert();
+
+    assert_eq!(client.token().await, Some(token));
+
+    let debug = format!("{:?}", client);
+
+    assert!(debug.contains("test_user"));
+    assert!(!debug.contains("TEST_USER_KEY"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_login_invalid_token_payload() {
     let client = test_client();
 
     let now = now_round_seconds();
-    let exp = now + Duration::seconds(TOKEN_EXP_LIMIT - TOKEN_EXP_LIMIT / 2);
-    let token = create_jwt(&TokenPayload { orig_iat: now, exp });
+    let token = create_jwt(&TokenPayload {
+        orig_iat: now,
+        exp: now - Duration::hours(1),
+    });
 
-    let req_body = json!({ "apikey": API_KEY });
-    let res_body = serde_json::to_string(&json!({ "token": token })).unwrap();
+    let login_mock = mock(POST, LOGIN_PATH)
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .create();
 
-    let login_mock = mock(POST, LOGIN_PATH).with_body(res_body.clone()).create();
+    match client.login_set_token().await.unwrap_err() {
+        Error::InvalidTokenPayload => {}
+        e => wrong_error_kind(Error::InvalidTokenPayload, e),
+    }
 
-    let _ = client.login_set_token().await;
+    login_mock.assert();
+}
+
+#[tokio::test]
+async fn client_login_opaque_token() -> Result<()> {
+    let client = test_client();
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .with_body(serde_json::to_string(&json!({ "token": "opaque-token" })).unwrap())
+        .create();
+
+    let before = Utc::now();
+
+    client.login_set_token().await?;
 
     login_mock.assert();
 
-    let relogin_mock = mock(POST, LOGIN_PATH)
-        .match_body(Matcher::Json(req_body))
-        .with_body(res_body)
+    let token = client.token.lock().await;
+    let token = token.as_ref().unwrap();
+
+    assert_eq!(token.token, "opaque-token");
+    assert!(token.exp >= before + Duration::seconds(OPAQUE_TOKEN_LIFETIME));
+    assert!(token.exp <= Utc::now() + Duration::seconds(OPAQUE_TOKEN_LIFETIME));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_time_until_refresh() -> Result<()> {
+    let now = now_round_seconds();
+
+    let client = test_client();
+
+    assert_eq!(client.time_until_refresh().await, None);
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: now,
+        exp: now + Duration::hours(1),
+    });
+    let client = Client::with_token(API_KEY, token)?;
+
+    let until = client.time_until_refresh().await.unwrap();
+    let expected = Duration::hours(1) - Duration::seconds(TOKEN_EXP_LIMIT);
+
+    assert!(until <= expected.to_std().unwrap());
+    assert!(until >= (expected - Duration::seconds(10)).to_std().unwrap());
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: now,
+        exp: now + Duration::seconds(TOKEN_EXP_LIMIT / 2),
+    });
+    let client = Client::with_token(API_KEY, token)?;
+
+    assert_eq!(
+        client.time_until_refresh().await,
+        Some(std::time::Duration::from_secs(0))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_authenticate() -> Result<()> {
+    let client = authenticated_test_client().await;
+    let old_token = client.export_token().await.unwrap();
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: now_round_seconds() + Duration::seconds(1),
+        exp: now_round_seconds() + Duration::days(1),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
         .create();
 
-    let series_mock = auth_lang_mock(&client, GET, series_url().as_str()).create();
+    client.authenticate().await?;
+
+    login_mock.assert();
+
+    let new_token = client.export_token().await.unwrap();
+
+    assert_eq!(new_token.token, token);
+    assert_ne!(new_token, old_token);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_builder_preload_languages() -> Result<()> {
+    let client_builder = ClientBuilder {
+        base_url: Url::parse(&mockito::server_url())?,
+        ..Client::builder(API_KEY).preload_languages(true)
+    };
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(2),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .create();
+
+    let languages = json!({
+        "data": [
+            { "id": 7, "abbreviation": "en", "name": "English", "englishName": "English" },
+            { "id": 14, "abbreviation": "de", "name": "Deutsch", "englishName": "German" },
+        ]
+    });
+
+    let languages_mock = mock(GET, "/languages")
+        .match_header("authorization", format!("Bearer {}", token).as_str())
+        .with_body(languages.to_string())
+        .create();
+
+    let client = client_builder.build().await?;
+
+    login_mock.assert();
+    languages_mock.assert();
+
+    let german = client.language_by_abbr("de").await?.unwrap();
+    assert_eq!(german.id, LanguageID(14));
+    assert_eq!(client.language_by_abbr("xx").await?, None);
+
+    let languages = client
+        .languages_by_ids(&[LanguageID(7), LanguageID(99)])
+        .await?;
+    assert_eq!(languages[0].as_ref().unwrap().abbreviation, "en");
+    assert_eq!(languages[1], None);
+
+    // languages are requested only once
+    languages_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_builder_default_header() -> Result<()> {
+    let client_builder = ClientBuilder {
+        base_url: Url::parse(&mockito::server_url())?,
+        ..Client::builder(API_KEY)
+            .default_header("X-Request-ID", "test-id")
+            .default_header("Content-Type", "application/vnd.test+json")
+    };
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(3),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .match_header("x-request-id", "test-id")
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .create();
+
+    let client = client_builder.build().await?;
+
+    login_mock.assert();
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .match_header("x-request-id", "test-id")
+        .match_header("content-type", "application/vnd.test+json")
+        .create();
 
     let _ = client.series(SERIES_ID).await;
 
-    relogin_mock.assert();
     series_mock.assert();
 
     Ok(())
 }
 
-#[test]
-fn client_set_language() {
-    let mut client = test_client();
+#[tokio::test]
+async fn client_builder_invalid_default_header() {
+    match Client::builder(API_KEY)
+        .default_header("invalid header", "value")
+        .build()
+        .await
+        .unwrap_err()
+    {
+        Error::InvalidHeaderName(_) => {}
+        e => panic!("Wrong error kind: expected InvalidHeaderName, got {:?}", e),
+    }
 
-    assert_eq!(client.lang_abbr, "en".to_string());
+    match Client::builder(API_KEY)
+        .default_header("X-Valid", "invalid\nvalue")
+        .build()
+        .await
+        .unwrap_err()
+    {
+        Error::InvalidHeaderValue(_) => {}
+        e => panic!("Wrong error kind: expected InvalidHeaderValue, got {:?}", e),
+    }
+}
 
-    let mut abbr = "ts".to_string();
+#[tokio::test]
+async fn client_builder_settings() -> Result<()> {
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Shared-Pool", HeaderValue::from_static("yes"));
 
-    let language = &Language {
-        id: LanguageID(1),
-        abbreviation: abbr.clone(),
-        name: "Test".to_string(),
-        english_name: "Test".to_string(),
+    let http_client = HttpClient::builder().default_headers(headers).build()?;
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .match_header("X-Shared-Pool", "yes")
+        .match_body(Matcher::Json(json!({ "apikey": "OTHER_API_KEY" })))
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .create();
+
+    let client = Client::builder(API_KEY)
+        .base_url(&mockito::server_url())
+        .api_key("OTHER_API_KEY")
+        .language_abbr("ko")
+        .timeout(std::time::Duration::from_secs(5))
+        .http_client(http_client)
+        .build()
+        .await?;
+
+    login_mock.assert();
+
+    assert_eq!(client.language_abbr(), "ko");
+    assert_eq!(client.timeout, Some(std::time::Duration::from_secs(5)));
+
+    let german = Language {
+        id: LanguageID(14),
+        abbreviation: "de".to_string(),
+        name: "Deutsch".to_string(),
+        english_name: "German".to_string(),
     };
 
-    client.set_language(language);
+    let client = Client::builder(API_KEY)
+        .base_url(&mockito::server_url())
+        .language(&german)
+        .token(token)
+        .build()
+        .await?;
 
-    assert_eq!(client.lang_abbr, abbr);
+    assert_eq!(client.language_abbr(), "de");
 
-    abbr = "st".to_string();
+    Ok(())
+}
 
-    client.set_language_abbr(abbr.clone());
+#[tokio::test]
+async fn client_builder_empty_api_key() {
+    match Client::builder("").build().await.unwrap_err() {
+        Error::MissingAPIKey => {}
+        e => panic!("Wrong error kind: expected MissingAPIKey, got {:?}", e),
+    }
 
-    assert_eq!(client.lang_abbr, abbr);
+    match Client::builder(API_KEY)
+        .api_key("")
+        .build()
+        .await
+        .unwrap_err()
+    {
+        Error::MissingAPIKey => {}
+        e => panic!("Wrong error kind: expected MissingAPIKey, got {:?}", e),
+    }
 }
 
 #[tokio::test]
-async fn client_search() {
-    let client = authenticated_test_client().await;
+async fn client_timeout() {
+    let client = Client {
+        timeout: Some(std::time::Duration::from_millis(1)),
+        ..authenticated_test_client().await
+    };
 
-    let name = "test+series".to_string();
-    let imdb_id = "tttest".to_string();
-    let zap2it_id = "1234567".to_string();
-    let slug = "slug-test".to_string();
+    let _series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_body_from_fn(|w| {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            w.write_all(b"{}")
+        })
+        .create();
 
-    let cases = vec![
-        ("name", name.clone(), SearchBy::Name(&name)),
-        ("imdbId", imdb_id.clone(), SearchBy::IMDbID(&imdb_id)),
-        (
-            "zap2itId",
-            zap2it_id.clone(),
-            SearchBy::Zap2itID(&zap2it_id),
-        ),
-        ("slug", slug.clone(), SearchBy::Slug(&slug)),
-    ];
+    match client.series(SERIES_ID).await.unwrap_err() {
+        Error::Http(e) => assert!(e.is_timeout()),
+        e => panic!("Wrong error kind: expected Http, got {:?}", e),
+    }
+}
 
-    for (query_key, query_value, search_by) in cases.into_iter() {
-        let mock = auth_lang_mock(&client, GET, SEARCH_PATH)
-            .match_query(UrlEncoded(query_key.to_string(), query_value))
-            .create();
+#[tokio::test]
+async fn client_builder_base_url() -> Result<()> {
+    let base_url = format!("{}/proxy/thetvdb", mockito::server_url());
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let login_mock = mock(POST, "/proxy/thetvdb/login")
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .create();
+
+    let client = Client::builder(API_KEY).base_url(&base_url).build().await?;
+
+    login_mock.assert();
+
+    assert_eq!(
+        client.series_url(SeriesID(1)).as_str(),
+        format!("{}/series/1", base_url)
+    );
+
+    match Client::builder(API_KEY)
+        .base_url("not a url")
+        .build()
+        .await
+        .unwrap_err()
+    {
+        Error::InvalidUrl(_) => {}
+        e => panic!("Wrong error kind: expected InvalidUrl, got {:?}", e),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_max_concurrent_requests() -> Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    const MAX: usize = 2;
+    const REQUESTS: usize = 6;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(std::sync::Mutex::new(0));
+    let total = Arc::new(AtomicUsize::new(0));
+
+    let (server_in_flight, server_max, server_total) =
+        (in_flight.clone(), max_in_flight.clone(), total.clone());
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let (in_flight, max_in_flight, total) = (
+                server_in_flight.clone(),
+                server_max.clone(),
+                server_total.clone(),
+            );
+
+            tokio::spawn(async move {
+                let mut buf = [0; 4096];
+
+                // every request is a GET without body, so a read ending with
+                // an empty line is a whole request
+                while let Ok(n) = socket.read(&mut buf).await {
+                    if n == 0 || !buf[..n].ends_with(b"\r\n\r\n") {
+                        break;
+                    }
+
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    {
+                        let mut max = max_in_flight.lock().unwrap();
+                        *max = (*max).max(current);
+                    }
+                    total.fetch_add(1, Ordering::SeqCst);
+
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                    let body = r#"{"data":[]}"#;
+                    let res = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+
+                    if socket.write_all(res.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let client = Client {
+        base_url: Url::parse(&format!("http://{}", addr))?,
+        request_semaphore: Some(Arc::new(Semaphore::new(MAX))),
+        ..Client::with_token(API_KEY, token)?
+    };
+
+    let results =
+        futures::future::join_all((0..REQUESTS).map(|_| client.series_actors(SERIES_ID))).await;
+
+    for result in results {
+        assert_eq!(result?, vec![]);
+    }
+
+    assert_eq!(total.load(Ordering::SeqCst), REQUESTS);
+    assert_eq!(*max_in_flight.lock().unwrap(), MAX);
+
+    Ok(())
+}
+
+#[test]
+fn client_builder_token() {
+    let builder = Client::builder(API_KEY);
+
+    assert_eq!(builder.token, None);
+    assert!(builder.manage_token);
+
+    let builder = builder.token("token").manage_token(false);
+
+    assert_eq!(builder.token, Some("token".to_string()));
+    assert!(!builder.manage_token);
+}
+
+#[test]
+fn client_builder_verify_ids() {
+    assert!(!Client::builder(API_KEY).verify_ids);
+    assert!(Client::builder(API_KEY).verify_ids(true).verify_ids);
+}
+
+#[tokio::test]
+async fn client_always_send_language() -> Result<()> {
+    let client = Client {
+        lang_abbr: "de".to_string(),
+        always_send_language: true,
+        ..authenticated_test_client().await
+    };
+
+    let url = format!("/series/{}/actors", SERIES_ID);
+
+    let actors_mock = auth_mock(&client, GET, url.as_str())
+        .match_header("accept-language", "de")
+        .with_body(json!({ "data": [] }).to_string())
+        .create();
+
+    client.series_actors(SERIES_ID).await?;
+
+    actors_mock.assert();
+
+    let fr_actors_mock = auth_mock(&client, GET, url.as_str())
+        .match_header("accept-language", "fr")
+        .with_body(json!({ "data": [] }).to_string())
+        .create();
+
+    client.series_actors_in_language(SERIES_ID, "fr").await?;
+
+    fr_actors_mock.assert();
+
+    Ok(())
+}
+
+#[test]
+fn client_builder_max_concurrent_requests() {
+    let builder = Client::builder(API_KEY).max_concurrent_requests(3);
+
+    assert_eq!(builder.max_concurrent_requests, Some(3));
+}
+
+#[tokio::test]
+async fn client_builder_max_concurrent_requests_zero() {
+    let result = Client::builder(API_KEY)
+        .base_url(&mockito::server_url())
+        .max_concurrent_requests(0)
+        .build()
+        .await;
+
+    match result.unwrap_err() {
+        Error::InvalidConcurrencyLimit => {}
+        e => panic!(
+            "Wrong error kind: expected InvalidConcurrencyLimit, got {:?}",
+            e
+        ),
+    }
+}
+
+#[test]
+fn retry_config_delay() {
+    use std::time::Duration as StdDuration;
+
+    let config = RetryConfig::new(5).strategy(RetryStrategy::Exponential {
+        base: StdDuration::from_millis(100),
+        max: StdDuration::from_millis(500),
+    });
+
+    let delays: Vec<_> = (1..=5).map(|a| config.delay(a)).collect();
+    let expected: Vec<_> = vec![100, 200, 400, 500, 500]
+        .into_iter()
+        .map(StdDuration::from_millis)
+        .collect();
+
+    assert_eq!(delays, expected);
+    assert_eq!(config.delay(u32::MAX), StdDuration::from_millis(500));
+
+    let fixed = StdDuration::from_secs(2);
+    let config = RetryConfig::new(3).strategy(RetryStrategy::Fixed(fixed));
+
+    assert!((1..=3).all(|a| config.delay(a) == fixed));
+
+    let config = config.jitter(true);
+
+    assert!((1..=100).all(|a| config.delay(a) <= fixed));
+
+    let config = RetryConfig::new(3).strategy(RetryStrategy::None);
+
+    assert_eq!(config.delay(1), StdDuration::from_secs(0));
+}
+
+#[tokio::test]
+async fn client_retry() -> Result<()> {
+    let mut client = authenticated_test_client().await;
+
+    client.retry = Some(
+        RetryConfig::new(2).strategy(RetryStrategy::Fixed(std::time::Duration::from_millis(1))),
+    );
+
+    let error_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_status(503)
+        .expect(2)
+        .create();
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_body(json!({ "data": { "id": SERIES_ID } }).to_string())
+        .create();
+
+    client
+        .series_into::<serde_json::Value, _>(SERIES_ID)
+        .await?;
+
+    error_mock.assert();
+    series_mock.assert();
+
+    drop(series_mock);
+
+    let retries = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let hook_retries = retries.clone();
+
+    client.on_retry = Some(RetryHook::new(move |attempt, error, delay| {
+        hook_retries
+            .lock()
+            .unwrap()
+            .push((attempt, error.to_string(), delay));
+    }));
+
+    let error_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_status(500)
+        .expect(3)
+        .create();
+
+    match client.series(SERIES_ID).await.unwrap_err() {
+        Error::ServerError => {}
+        e => wrong_error_kind(Error::ServerError, e),
+    }
+
+    error_mock.assert();
+
+    let delay = std::time::Duration::from_millis(1);
+    assert_eq!(
+        *retries.lock().unwrap(),
+        vec![
+            (1, Error::ServerError.to_string(), delay),
+            (2, Error::ServerError.to_string(), delay)
+        ]
+    );
+
+    let not_found_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_status(404)
+        .expect(1)
+        .create();
+
+    let _ = client.series(SERIES_ID).await;
+
+    not_found_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_not_found_message() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let not_found_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_status(404)
+        .with_body(json!({ "Error": "ID: 318408 not found" }).to_string())
+        .create();
+
+    let error = client.series(SERIES_ID).await.unwrap_err();
+
+    not_found_mock.assert();
+
+    assert_eq!(error.to_string(), "Not found: ID: 318408 not found");
+    match error {
+        Error::NotFound(Some(message)) => assert_eq!(message, "ID: 318408 not found"),
+        e => wrong_error_kind(Error::NotFound(None), e),
+    }
+
+    drop(not_found_mock);
+
+    let no_body_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_status(404)
+        .create();
+
+    match client.series(SERIES_ID).await.unwrap_err() {
+        Error::NotFound(None) => {}
+        e => wrong_error_kind(Error::NotFound(None), e),
+    }
+
+    no_body_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_rate_limited() -> Result<()> {
+    use std::time::Duration as StdDuration;
+
+    let client = authenticated_test_client().await;
+
+    let rate_limited = |retry_after: Option<&str>| {
+        let mock = auth_lang_mock(&client, GET, series_url().as_str()).with_status(429);
+
+        match retry_after {
+            Some(retry_after) => mock.with_header("Retry-After", retry_after).create(),
+            None => mock.create(),
+        }
+    };
+
+    let retry_after = |error: Error| match error {
+        Error::RateLimited { retry_after } => retry_after,
+        e => panic!("Wrong error kind: expected RateLimited, got {:?}", e),
+    };
+
+    let mock = rate_limited(Some("120"));
+    let error = client.series(SERIES_ID).await.unwrap_err();
+    mock.assert();
+
+    assert_eq!(error.to_string(), "Rate limited, retry after 120 seconds");
+    assert_eq!(retry_after(error), Some(StdDuration::from_secs(120)));
+    drop(mock);
+
+    let date = (Utc::now() + Duration::seconds(60)).to_rfc2822();
+    let mock = rate_limited(Some(&date));
+    let delay = retry_after(client.series(SERIES_ID).await.unwrap_err()).unwrap();
+    mock.assert();
+
+    assert!(delay > StdDuration::from_secs(50) && delay <= StdDuration::from_secs(60));
+    drop(mock);
+
+    let mock = rate_limited(Some("Wed, 21 Oct 2015 07:28:00 GMT"));
+    assert_eq!(
+        retry_after(client.series(SERIES_ID).await.unwrap_err()),
+        Some(StdDuration::from_secs(0))
+    );
+    mock.assert();
+    drop(mock);
+
+    let mock = rate_limited(None);
+    assert_eq!(
+        retry_after(client.series(SERIES_ID).await.unwrap_err()),
+        None
+    );
+    mock.assert();
+
+    Ok(())
+}
+
+#[test]
+fn client_builder_retry() {
+    assert_eq!(Client::builder(API_KEY).retry, None);
+    assert_eq!(
+        Client::builder(API_KEY).retry(RetryConfig::new(3)).retry,
+        Some(RetryConfig::new(3))
+    );
+}
+
+#[test]
+fn client_language_abbr() {
+    let mut client = test_client();
+
+    assert_eq!(client.language_abbr(), "en");
+    assert!(client.is_default_language());
+
+    client.set_language_abbr("ko");
+
+    assert_eq!(client.language_abbr(), "ko");
+    assert!(!client.is_default_language());
+}
+
+#[tokio::test]
+async fn client_relogin_on_token_exp() -> Result<()> {
+    let client = test_client();
+
+    let now = now_round_seconds();
+    let exp = now + Duration::seconds(TOKEN_EXP_LIMIT - TOKEN_EXP_LIMIT / 2);
+    let token = create_jwt(&TokenPayload { orig_iat: now, exp });
+
+    let req_body = json!({ "apikey": API_KEY });
+    let res_body = serde_json::to_string(&json!({ "token": token })).unwrap();
+
+    let login_mock = mock(POST, LOGIN_PATH).with_body(res_body.clone()).create();
+
+    let _ = client.login_set_token().await;
+
+    login_mock.assert();
+
+    // the API rejects the refresh, so the client logs in again
+    let refresh_mock = mock(GET, REFRESH_TOKEN_PATH)
+        .match_header("authorization", format!("Bearer {}", token).as_str())
+        .with_status(401)
+        .create();
+
+    let relogin_mock = mock(POST, LOGIN_PATH)
+        .match_body(Matcher::Json(req_body))
+        .with_body(res_body)
+        .create();
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str()).create();
+
+    let _ = client.series(SERIES_ID).await;
+
+    refresh_mock.assert();
+    relogin_mock.assert();
+    series_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_concurrent_login_once() -> Result<()> {
+    // no token yet, so every request needs to wait for a login
+    let client = test_client();
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .expect(1)
+        .create();
+
+    let series_mock = mock(GET, series_url().as_str())
+        .match_header("authorization", format!("Bearer {}", token).as_str())
+        .expect(8)
+        .create();
+
+    futures::future::join_all(
+        (0..8).map(|_| client.series_into::<serde_json::Value, _>(SERIES_ID)),
+    )
+    .await;
+
+    login_mock.assert();
+    series_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_relogin_on_unauthorized() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let stale_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_status(401)
+        .expect(1)
+        .create();
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: now_round_seconds() + Duration::seconds(1),
+        exp: now_round_seconds() + Duration::days(1),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .expect(1)
+        .create();
+
+    let series_mock = mock(GET, series_url().as_str())
+        .match_header("authorization", format!("Bearer {}", token).as_str())
+        .with_body(json!({ "data": { "id": SERIES_ID } }).to_string())
+        .create();
+
+    client
+        .series_into::<serde_json::Value, _>(SERIES_ID)
+        .await?;
+
+    stale_mock.assert();
+    login_mock.assert();
+    series_mock.assert();
+
+    assert_eq!(client.token().await, Some(token.clone()));
+
+    drop(series_mock);
+
+    // requests are replayed only once
+    let unauthorized_mock = mock(GET, series_url().as_str())
+        .with_status(401)
+        .expect(2)
+        .create();
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .expect(1)
+        .create();
+
+    match client.series(SERIES_ID).await.unwrap_err() {
+        Error::InvalidAPIKey => {}
+        e => wrong_error_kind(Error::InvalidAPIKey, e),
+    }
+
+    unauthorized_mock.assert();
+    login_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_refresh_token_near_exp() -> Result<()> {
+    let now = now_round_seconds();
+    let exp = now + Duration::seconds(TOKEN_EXP_LIMIT / 2);
+    let old_token = create_jwt(&TokenPayload { orig_iat: now, exp });
+
+    let client = Client {
+        base_url: Url::parse(&mockito::server_url()).unwrap(),
+        ..Client::with_token(API_KEY, old_token.clone())?
+    };
+
+    let new_token = create_jwt(&TokenPayload {
+        orig_iat: now,
+        exp: now + Duration::days(1),
+    });
+
+    let refresh_mock = mock(GET, REFRESH_TOKEN_PATH)
+        .match_header("authorization", format!("Bearer {}", old_token).as_str())
+        .with_body(serde_json::to_string(&json!({ "token": new_token })).unwrap())
+        .create();
+
+    let login_mock = mock(POST, LOGIN_PATH).expect(0).create();
+
+    let series_mock = mock(GET, series_url().as_str())
+        .match_header("authorization", format!("Bearer {}", new_token).as_str())
+        .create();
+
+    let _ = client.series(SERIES_ID).await;
+
+    refresh_mock.assert();
+    login_mock.assert();
+    series_mock.assert();
+
+    assert_eq!(client.token_expiry().await, Some(now + Duration::days(1)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_clone_shares_state() -> Result<()> {
+    let client = test_client();
+    let mut clone = client.clone();
+
+    clone.set_language_abbr("ko");
+
+    assert_eq!(client.language_abbr(), DEFAULT_LANG_ABBR);
+    assert_eq!(clone.language_abbr(), "ko");
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .expect(1)
+        .create();
+
+    clone.login_set_token().await?;
+
+    login_mock.assert();
+
+    assert_eq!(client.token().await, Some(token));
+
+    let languages_mock = auth_mock(&client, GET, "/languages")
+        .with_body(
+            json!({
+                "data": [
+                    { "id": 32, "abbreviation": "ko", "name": "한국어", "englishName": "Korean" },
+                ]
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    client.language_by_abbr("ko").await?;
+    clone.language_by_abbr("ko").await?;
+
+    // languages are cached for all clones
+    languages_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_new_lazy() -> Result<()> {
+    let login_mock = mock(POST, LOGIN_PATH).expect(0).create();
+
+    let client = Client {
+        base_url: Url::parse(&mockito::server_url()).unwrap(),
+        ..Client::new_lazy(API_KEY)
+    };
+
+    login_mock.assert();
+    assert_eq!(client.token().await, None);
+
+    drop(login_mock);
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .create();
+
+    let series_mock = mock(GET, series_url().as_str())
+        .match_header("authorization", format!("Bearer {}", token).as_str())
+        .create();
+
+    let _ = client.series(SERIES_ID).await;
+
+    login_mock.assert();
+    series_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_with_http_client() -> Result<()> {
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Shared-Pool", HeaderValue::from_static("yes"));
+
+    let http_client = HttpClient::builder().default_headers(headers).build()?;
+
+    let client = Client {
+        base_url: Url::parse(&mockito::server_url()).unwrap(),
+        ..Client::create_with_http_client(API_KEY, http_client)
+    };
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .match_header("X-Shared-Pool", "yes")
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .create();
+
+    client.login_set_token().await?;
+
+    login_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_with_token() -> Result<()> {
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let client = Client {
+        base_url: Url::parse(&mockito::server_url()).unwrap(),
+        ..Client::with_token(API_KEY, token.clone())?
+    };
+
+    let login_mock = mock(POST, LOGIN_PATH).expect(0).create();
+
+    let series_mock = mock(GET, series_url().as_str())
+        .match_header("authorization", format!("Bearer {}", token).as_str())
+        .create();
+
+    let _ = client.series(SERIES_ID).await;
+
+    login_mock.assert();
+    series_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_builder_api_version() -> Result<()> {
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let default_mock = mock(POST, LOGIN_PATH)
+        .match_header("accept", "application/vnd.thetvdb.v3.0.0")
+        .with_body(json!({ "token": token }).to_string())
+        .create();
+
+    let client = test_client();
+    client.login_set_token().await?;
+
+    default_mock.assert();
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .match_header("accept", "application/vnd.thetvdb.v2.2.0")
+        .with_body(json!({ "token": token }).to_string())
+        .create();
+
+    let client = ClientBuilder {
+        base_url: Url::parse(&mockito::server_url())?,
+        ..Client::builder(API_KEY).api_version("2.2.0")
+    }
+    .build()
+    .await?;
+
+    login_mock.assert();
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .match_header("accept", "application/vnd.thetvdb.v2.2.0")
+        .create();
+
+    let _ = client.series(SERIES_ID).await;
+
+    series_mock.assert();
+
+    match Client::builder(API_KEY)
+        .api_version("3\n")
+        .build()
+        .await
+        .unwrap_err()
+    {
+        Error::InvalidHeaderValue(_) => {}
+        e => panic!("Wrong error kind: expected InvalidHeaderValue, got {:?}", e),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_builder_manage_token() -> Result<()> {
+    let expired_token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now() - Duration::days(2),
+        exp: Utc::now() - Duration::days(1),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH).expect(0).create();
+
+    let client_builder = ClientBuilder {
+        base_url: Url::parse(&mockito::server_url())?,
+        ..Client::builder(API_KEY)
+            .token(expired_token.clone())
+            .manage_token(false)
+    };
+
+    let client = client_builder.build().await?;
+
+    let series_mock = mock(GET, series_url().as_str())
+        .match_header(
+            "authorization",
+            format!("Bearer {}", expired_token).as_str(),
+        )
+        .expect(2)
+        .create();
+
+    let _ = client.series(SERIES_ID).await;
+    let _ = client.series(SERIES_ID).await;
+
+    login_mock.assert();
+    series_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_export_token() -> Result<()> {
+    let now = now_round_seconds();
+    let exp = now + Duration::days(1);
+    let token = create_jwt(&TokenPayload { orig_iat: now, exp });
+
+    assert_eq!(test_client().export_token().await, None);
+
+    let client = Client::with_token(API_KEY, token.clone())?;
+
+    assert_eq!(
+        client.export_token().await,
+        Some(TokenSnapshot {
+            token,
+            created: now,
+            exp
+        })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_token_and_expiry() -> Result<()> {
+    let now = now_round_seconds();
+    let exp = now + Duration::days(1);
+    let token = create_jwt(&TokenPayload { orig_iat: now, exp });
+
+    let client = test_client();
+
+    assert_eq!(client.token().await, None);
+    assert_eq!(client.token_expiry().await, None);
+
+    let client = Client::with_token(API_KEY, token.clone())?;
+
+    assert_eq!(client.token().await, Some(token));
+    assert_eq!(client.token_expiry().await, Some(exp));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_with_expired_token() -> Result<()> {
+    let expired = create_jwt(&TokenPayload {
+        orig_iat: Utc::now() - Duration::days(2),
+        exp: Utc::now() - Duration::days(1),
+    });
+
+    let client = Client {
+        base_url: Url::parse(&mockito::server_url()).unwrap(),
+        ..Client::with_token(API_KEY, expired)?
+    };
+
+    let token = create_jwt(&TokenPayload {
+        orig_iat: Utc::now(),
+        exp: Utc::now() + Duration::days(1),
+    });
+
+    let login_mock = mock(POST, LOGIN_PATH)
+        .with_body(serde_json::to_string(&json!({ "token": token })).unwrap())
+        .create();
+
+    let series_mock = mock(GET, series_url().as_str())
+        .match_header("authorization", format!("Bearer {}", token).as_str())
+        .create();
+
+    let _ = client.series(SERIES_ID).await;
+
+    login_mock.assert();
+    series_mock.assert();
+
+    assert_eq!(client.token().await, Some(token));
+
+    Ok(())
+}
+
+#[test]
+fn client_set_language() {
+    let mut client = test_client();
+
+    assert_eq!(client.lang_abbr, "en".to_string());
+
+    let mut abbr = "ts".to_string();
+
+    let language = &Language {
+        id: LanguageID(1),
+        abbreviation: abbr.clone(),
+        name: "Test".to_string(),
+        english_name: "Test".to_string(),
+    };
+
+    client.set_language(language);
+
+    assert_eq!(client.lang_abbr, abbr);
+
+    abbr = "st".to_string();
+
+    client.set_language_abbr(abbr.clone());
+
+    assert_eq!(client.lang_abbr, abbr);
+}
+
+#[tokio::test]
+async fn client_search() {
+    let client = authenticated_test_client().await;
+
+    let name = "test+series".to_string();
+    let imdb_id = "tttest".to_string();
+    let zap2it_id = "1234567".to_string();
+    let slug = "slug-test".to_string();
+
+    let cases = vec![
+        ("name", name.clone(), SearchBy::Name(&name)),
+        ("imdbId", imdb_id.clone(), SearchBy::IMDbID(&imdb_id)),
+        (
+            "zap2itId",
+            zap2it_id.clone(),
+            SearchBy::Zap2itID(&zap2it_id),
+        ),
+        ("slug", slug.clone(), SearchBy::Slug(&slug)),
+    ];
+
+    for (query_key, query_value, search_by) in cases.into_iter() {
+        let mock = auth_lang_mock(&client, GET, SEARCH_PATH)
+            .match_query(UrlEncoded(query_key.to_string(), query_value))
+            .create();
+
+        let _ = client.search(search_by).await;
+
+        mock.assert();
+    }
+}
+
+#[tokio::test]
+async fn client_search_no_results() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let not_found_mock = auth_lang_mock(&client, GET, SEARCH_PATH)
+        .match_query(UrlEncoded("name".to_string(), "nothing".to_string()))
+        .with_status(404)
+        .with_body(json!({ "Error": "Resource not found" }).to_string())
+        .create();
+
+    assert_eq!(client.search(SearchBy::Name("nothing")).await?, vec![]);
+
+    not_found_mock.assert();
+
+    let page_not_found_mock = auth_lang_mock(&client, GET, SEARCH_PATH)
+        .match_query(AllOf(vec![
+            UrlEncoded("name".to_string(), "nothing".to_string()),
+            UrlEncoded("page".to_string(), "1".to_string()),
+        ]))
+        .with_status(404)
+        .with_body(json!({ "Error": "Resource not found" }).to_string())
+        .create();
+
+    let response = client.search_page(SearchBy::Name("nothing"), 1).await?;
+
+    page_not_found_mock.assert();
+
+    assert!(response.data.is_empty());
+    assert!(!response.is_paginated());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_search_page() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let query = AllOf(vec![
+        UrlEncoded("name".to_string(), "test".to_string()),
+        UrlEncoded("page".to_string(), "2".to_string()),
+    ]);
+
+    let series = vec![SearchSeries {
+        id: SeriesID(SERIES_ID),
+        ..Default::default()
+    }];
+
+    let flat_mock = auth_lang_mock(&client, GET, SEARCH_PATH)
+        .match_query(query.clone())
+        .with_body(json!({ "data": series }).to_string())
+        .create();
+
+    let response = client.search_page(SearchBy::Name("test"), 2).await?;
+
+    flat_mock.assert();
+
+    assert_eq!(response.data, series);
+    assert!(!response.is_paginated());
+    assert_eq!(response.next_page(), None);
+
+    drop(flat_mock);
+
+    let paginated_mock = auth_lang_mock(&client, GET, SEARCH_PATH)
+        .match_query(query)
+        .with_body(
+            json!({
+                "data": series,
+                "links": { "first": 1, "last": 3, "next": 3, "prev": 1 },
+            })
+            .to_string(),
+        )
+        .create();
+
+    let response = client.search_page(SearchBy::Name("test"), 2).await?;
+
+    paginated_mock.assert();
+
+    assert!(response.is_paginated());
+    assert_eq!(response.next_page(), Some(3));
+    assert_eq!(response.prev_page(), Some(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_by_zap2it() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let results = vec![SearchSeries {
+        id: SeriesID(SERIES_ID),
+        ..Default::default()
+    }];
+
+    let search_mock = auth_lang_mock(&client, GET, SEARCH_PATH)
+        .match_query(UrlEncoded("zap2itId".to_string(), "EP1234".to_string()))
+        .with_body(json!({ "data": results }).to_string())
+        .create();
+
+    let series_mock = series_mock(&client, SERIES_ID, None);
+
+    let series = client.series_by_zap2it("EP1234").await?;
+
+    search_mock.assert();
+    series_mock.assert();
+
+    assert_eq!(series.map(|s| s.id), Some(SeriesID(SERIES_ID)));
+
+    let not_found_mock = auth_lang_mock(&client, GET, SEARCH_PATH)
+        .match_query(UrlEncoded("zap2itId".to_string(), "EP0000".to_string()))
+        .with_status(404)
+        .create();
+
+    assert_eq!(client.series_by_zap2it("EP0000").await?, None);
+
+    not_found_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_by_imdb() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let results = vec![
+        SearchSeries {
+            id: SeriesID(SERIES_ID),
+            ..Default::default()
+        },
+        SearchSeries {
+            id: SeriesID(SERIES_ID + 1),
+            ..Default::default()
+        },
+    ];
+
+    let search_mock = auth_lang_mock(&client, GET, SEARCH_PATH)
+        .match_query(UrlEncoded("imdbId".to_string(), "tt5491994".to_string()))
+        .with_body(json!({ "data": results }).to_string())
+        .create();
+
+    let series_mock = series_mock(&client, SERIES_ID, None);
+
+    let series = client.series_by_imdb("tt5491994").await?;
+
+    search_mock.assert();
+    series_mock.assert();
+
+    assert_eq!(series.map(|s| s.id), Some(SeriesID(SERIES_ID)));
+
+    let not_found_mock = auth_lang_mock(&client, GET, SEARCH_PATH)
+        .match_query(UrlEncoded("imdbId".to_string(), "tt0000000".to_string()))
+        .with_status(404)
+        .create();
+
+    assert_eq!(client.series_by_imdb("tt0000000").await?, None);
+
+    not_found_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series() {
+    let client = authenticated_test_client().await;
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str()).create();
+
+    let _ = client.series(SERIES_ID).await;
+
+    series_mock.assert();
+}
+
+#[tokio::test]
+async fn client_decode_error() {
+    let client = authenticated_test_client().await;
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_body("{\"data\": {\"id\": ")
+        .create();
+
+    match client.series(SERIES_ID).await.unwrap_err() {
+        Error::Decode(e) => assert!(e.is_decode()),
+        e => panic!("Wrong error kind: expected Decode, got {:?}", e),
+    }
+
+    series_mock.assert();
+}
+
+#[tokio::test]
+async fn client_series_many() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let mocks: Vec<Mock> = (1..=3).map(|id| series_mock(&client, id, None)).collect();
+
+    let series = client.series_many(vec![3, 1, 2]).await?;
+
+    for mock in mocks {
+        mock.assert();
+    }
+
+    assert_eq!(
+        series.iter().map(|s| s.id).collect::<Vec<_>>(),
+        vec![SeriesID(3), SeriesID(1), SeriesID(2)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_many_partial() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let mocks = vec![series_mock(&client, 1, None), series_mock(&client, 3, None)];
+
+    let not_found_mock = auth_lang_mock(&client, GET, "/series/2")
+        .with_status(404)
+        .create();
+
+    let (series, failed) = client.series_many_partial(vec![3, 2, 1]).await;
+
+    for mock in mocks {
+        mock.assert();
+    }
+    not_found_mock.assert();
+
+    assert_eq!(
+        series.iter().map(|s| s.id).collect::<Vec<_>>(),
+        vec![SeriesID(3), SeriesID(1)]
+    );
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].0, SeriesID(2));
+    assert_eq!(failed[0].1.to_string(), Error::NotFound(None).to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_search_filtered() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let search_results: Vec<SearchSeries> = (1..=4)
+        .map(|id| SearchSeries {
+            id: SeriesID(id),
+            ..Default::default()
+        })
+        .collect();
+
+    let search_mock = auth_lang_mock(&client, GET, SEARCH_PATH)
+        .match_query(UrlEncoded("name".to_string(), "test".to_string()))
+        .with_body(serde_json::to_string(&json!({ "data": search_results })).unwrap())
+        .create();
+
+    let series_mocks = vec![
+        series_mock(&client, 1, Some("TV-G")),
+        series_mock(&client, 2, Some("TV-MA")),
+        series_mock(&client, 3, None),
+        series_mock(&client, 4, Some("TV-PG")),
+    ];
+
+    let series = client
+        .search_filtered(SearchBy::Name("test"), ContentRating::TVPG)
+        .await?;
+
+    search_mock.assert();
+    for mock in series_mocks {
+        mock.assert();
+    }
+
+    assert_eq!(
+        series.iter().map(|s| s.id).collect::<Vec<_>>(),
+        vec![SeriesID(1), SeriesID(4)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_verify_ids() -> Result<()> {
+    let mut client = authenticated_test_client().await;
+
+    #[derive(Debug, Deserialize)]
+    struct Name {
+        #[serde(rename = "seriesName")]
+        _name: Option<String>,
+    }
+
+    let body = json!({ "data": { "id": SERIES_ID + 1, "seriesName": "Test" } }).to_string();
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_body(body.as_str())
+        .expect(2)
+        .create();
+
+    client.series_into::<Name, _>(SERIES_ID).await?;
+
+    client.verify_ids = true;
+
+    match client.series_into::<Name, _>(SERIES_ID).await.unwrap_err() {
+        Error::IdMismatch {
+            requested,
+            returned,
+        } => {
+            assert_eq!(requested, u64::from(SERIES_ID));
+            assert_eq!(returned, u64::from(SERIES_ID + 1));
+        }
+        e => panic!("Wrong error kind: expected IdMismatch, got {:?}", e),
+    }
+
+    series_mock.assert();
+
+    let matching_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_body(json!({ "data": { "id": SERIES_ID, "seriesName": "Test" } }).to_string())
+        .create();
+
+    client.series_into::<Name, _>(SERIES_ID).await?;
+
+    matching_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_verify_ids_decode_error() {
+    let mut client = authenticated_test_client().await;
+
+    #[derive(Debug, Deserialize)]
+    struct Name {
+        #[serde(rename = "seriesName")]
+        _name: u32,
+    }
+
+    let body = json!({ "data": { "id": SERIES_ID, "seriesName": "Test" } }).to_string();
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_body(body.as_str())
+        .expect(2)
+        .create();
+
+    for verify_ids in &[false, true] {
+        client.verify_ids = *verify_ids;
+
+        match client.series_into::<Name, _>(SERIES_ID).await.unwrap_err() {
+            Error::Decode(_) => {}
+            e => panic!("Wrong error kind: expected Decode, got {:?}", e),
+        }
+    }
+
+    series_mock.assert();
+}
+
+#[tokio::test]
+async fn client_series_detail() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let series = Series {
+        id: SeriesID(SERIES_ID),
+        series_name: Some("Planet Earth II".to_string()),
+        ..Default::default()
+    };
+    let actors = vec![Actor {
+        id: 1,
+        series_id: SeriesID(SERIES_ID),
+        name: "David Attenborough".to_string(),
+        ..Default::default()
+    }];
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_body(serde_json::to_string(&json!({ "data": series })).unwrap())
+        .create();
+
+    let actors_url = format!("/series/{}/actors", SERIES_ID);
+
+    let actors_mock = auth_mock(&client, GET, actors_url.as_str())
+        .with_body(serde_json::to_string(&json!({ "data": actors })).unwrap())
+        .create();
+
+    let detail = client.series_detail(SERIES_ID).await?;
+
+    series_mock.assert();
+    actors_mock.assert();
+
+    assert_eq!(detail.series, series);
+    assert_eq!(detail.actors, actors);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_last_modified() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}", SERIES_ID);
+
+    let last_modified_mock = auth_mock(&client, HEAD, url.as_str()).create();
+
+    let _ = client.series_last_modified(SERIES_ID).await;
+
+    last_modified_mock.assert();
+}
+
+#[tokio::test]
+async fn client_series_actors() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/actors", SERIES_ID);
+
+    let actors_mock = auth_mock(&client, GET, url.as_str()).create();
+
+    let _ = client.series_actors(SERIES_ID).await;
+
+    actors_mock.assert();
+}
+
+#[tokio::test]
+async fn client_series_actors_in_language() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/actors", SERIES_ID);
+
+    let actors = vec![Actor {
+        role: "Erzähler".to_string(),
+        ..Default::default()
+    }];
+
+    let actors_mock = auth_mock(&client, GET, url.as_str())
+        .match_header("accept-language", "de")
+        .with_body(serde_json::to_string(&json!({ "data": actors })).unwrap())
+        .create();
+
+    let res = client.series_actors_in_language(SERIES_ID, "de").await?;
+
+    actors_mock.assert();
+
+    assert_eq!(res, actors);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_episodes() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes", SERIES_ID);
+
+    let cases = &[
+        EpisodeParams::new(SERIES_ID),
+        EpisodeParams::with_page(SERIES_ID, 32),
+    ];
+
+    for params in cases {
+        let mock = auth_mock(&client, GET, url.as_str())
+            .match_query(UrlEncoded("page".to_string(), params.page.to_string()))
+            .create();
+
+        let _ = client.series_episodes(params).await;
+
+        mock.assert();
+    }
+}
+
+#[tokio::test]
+async fn client_series_episodes_all() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes", SERIES_ID);
+
+    let page_mock = |page: u16, ids: &[u32], next: Option<u16>, prev: Option<u16>| {
+        auth_mock(&client, GET, url.as_str())
+            .match_query(UrlEncoded("page".to_string(), page.to_string()))
+            .with_body(episode_page_body(ids, 1, 3, next, prev))
+            .expect(2)
+            .create()
+    };
+
+    let page_mocks = vec![
+        page_mock(1, &[1, 2], Some(2), None),
+        page_mock(2, &[3, 4], Some(3), Some(1)),
+        page_mock(3, &[5], None, Some(2)),
+    ];
+
+    let episodes = client.series_episodes_all(SERIES_ID).await?;
+
+    assert_eq!(
+        episodes.iter().map(|e| e.id.0).collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert!(episodes.iter().all(|e| e.series_id == SeriesID(SERIES_ID)));
+
+    let ids: Vec<serde_json::Value> = client.series_episodes_all_into(SERIES_ID).await?;
+
+    assert_eq!(ids.len(), 5);
+
+    for mock in page_mocks {
+        mock.assert();
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_episodes_stream() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes", SERIES_ID);
+
+    let page_mock = |page: u16, ids: &[u32], next: Option<u16>, prev: Option<u16>| {
+        auth_mock(&client, GET, url.as_str())
+            .match_query(UrlEncoded("page".to_string(), page.to_string()))
+            .with_body(episode_page_body(ids, 1, 3, next, prev))
+            .create()
+    };
+
+    let first_mock = page_mock(1, &[1, 2], Some(2), None);
+    let second_mock = page_mock(2, &[3, 4], Some(3), Some(1));
+    let last_mock = page_mock(3, &[5], None, Some(2));
+
+    let params = EpisodeParams::new(SERIES_ID);
+    let mut stream = Box::pin(client.series_episodes_stream(&params));
+
+    assert_eq!(stream.try_next().await?.unwrap().id, EpisodeID(1));
+
+    first_mock.assert();
+    // pages are requested only when needed
+    assert!(!second_mock.matched());
+
+    let rest: Vec<Episode> = stream.try_collect().await?;
+
+    second_mock.assert();
+    last_mock.assert();
+
+    assert_eq!(
+        rest.iter().map(|e| e.id.0).collect::<Vec<_>>(),
+        vec![2, 3, 4, 5]
+    );
+
+    drop(second_mock);
+
+    let _error_mock = auth_mock(&client, GET, url.as_str())
+        .match_query(UrlEncoded("page".to_string(), "2".to_string()))
+        .with_status(500)
+        .create();
+
+    let results: Vec<Result<Episode>> = client.series_episodes_stream(&params).collect().await;
+
+    assert_eq!(results.len(), 3);
+    assert!(results[..2].iter().all(|r| r.is_ok()));
+
+    match results.into_iter().last().unwrap().unwrap_err() {
+        Error::ServerError => {}
+        e => panic!("Wrong error kind: expected ServerError, got {:?}", e),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_episodes_reverse_stream() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes", SERIES_ID);
+
+    let page_mock = |page: u16, ids: &[u32], next: Option<u16>, prev: Option<u16>| {
+        auth_mock(&client, GET, url.as_str())
+            .match_query(UrlEncoded("page".to_string(), page.to_string()))
+            .with_body(episode_page_body(ids, 1, 2, next, prev))
+            .create()
+    };
+
+    let first_mock = page_mock(1, &[1, 2], Some(2), None);
+    let last_mock = page_mock(2, &[3, 4], None, Some(1));
+
+    let episodes: Vec<Episode> = client
+        .series_episodes_reverse_stream(&EpisodeParams::new(SERIES_ID))
+        .try_collect()
+        .await?;
+
+    let ids: Vec<EpisodeID> = episodes.iter().map(|e| e.id).collect();
+
+    assert_eq!(
+        ids,
+        vec![EpisodeID(4), EpisodeID(3), EpisodeID(2), EpisodeID(1)]
+    );
+
+    first_mock.assert();
+    last_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_episodes_missing_images() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes", SERIES_ID);
+
+    let episodes: Vec<Episode> = vec![(1, None), (2, Some("image.jpg")), (3, None)]
+        .into_iter()
+        .map(|(id, filename)| Episode {
+            id: EpisodeID(id),
+            filename: filename.map(str::to_string),
+            ..Default::default()
+        })
+        .collect();
+
+    let episodes_mock = auth_mock(&client, GET, url.as_str())
+        .match_query(UrlEncoded("page".to_string(), "1".to_string()))
+        .with_body(
+            serde_json::to_string(&json!({
+                "data": episodes,
+                "links": { "first": 1, "last": 1, "next": null, "prev": null },
+            }))
+            .unwrap(),
+        )
+        .create();
+
+    let missing = client.series_episodes_missing_images(SERIES_ID).await?;
+
+    assert_eq!(
+        missing.iter().map(|e| e.id).collect::<Vec<_>>(),
+        vec![EpisodeID(1), EpisodeID(3)]
+    );
+
+    episodes_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_top_episodes() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes", SERIES_ID);
+
+    let episodes: Vec<Episode> = vec![
+        (1, Some(7.5), 10),
+        (2, None, 0),
+        (3, Some(9.0), 4),
+        (4, Some(7.5), 25),
+        (5, Some(8.1), 2),
+    ]
+    .into_iter()
+    .map(|(id, site_rating, site_rating_count)| Episode {
+        id: EpisodeID(id),
+        site_rating,
+        site_rating_count,
+        ..Default::default()
+    })
+    .collect();
+
+    let episodes_mock = auth_mock(&client, GET, url.as_str())
+        .match_query(UrlEncoded("page".to_string(), "1".to_string()))
+        .with_body(
+            serde_json::to_string(&json!({
+                "data": episodes,
+                "links": { "first": 1, "last": 1, "next": null, "prev": null },
+            }))
+            .unwrap(),
+        )
+        .expect(2)
+        .create();
+
+    let ids = |episodes: Vec<Episode>| episodes.iter().map(|e| e.id.0).collect::<Vec<_>>();
+
+    assert_eq!(
+        ids(client.series_top_episodes(SERIES_ID, 10).await?),
+        vec![3, 5, 4, 1]
+    );
+    assert_eq!(
+        ids(client.series_top_episodes(SERIES_ID, 2).await?),
+        vec![3, 5]
+    );
+
+    episodes_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_watch_order() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes", SERIES_ID);
+
+    let date = |month, day| NaiveDate::from_ymd_opt(2020, month, day);
+
+    let episode = |id, season, number, aired| Episode {
+        id: EpisodeID(id),
+        aired_season: Some(season),
+        aired_episode_number: number,
+        first_aired: aired,
+        ..Default::default()
+    };
+
+    let mut episodes = vec![
+        episode(21, 2, 1, date(6, 1)),
+        episode(12, 1, 2, date(1, 8)),
+        episode(11, 1, 1, date(1, 1)),
+        episode(22, 2, 2, date(6, 8)),
+        episode(13, 1, 3, date(1, 15)),
+    ];
+
+    // before season 2 episode 2
+    let mut special = episode(1, 0, 1, None);
+    special.airs_before_season = Some(2);
+    special.airs_before_episode = Some(2);
+    episodes.push(special);
+
+    // after season 1
+    let mut special = episode(2, 0, 2, None);
+    special.airs_after_season = Some(1);
+    episodes.push(special);
+
+    // before season 1
+    let mut special = episode(3, 0, 3, None);
+    special.airs_before_season = Some(1);
+    episodes.push(special);
+
+    // no placement, aired between episodes 1 and 2 of season 1
+    episodes.push(episode(4, 0, 4, date(1, 4)));
+
+    // no placement or air date
+    episodes.push(episode(5, 0, 5, None));
+
+    let episodes_mock = auth_mock(&client, GET, url.as_str())
+        .match_query(UrlEncoded("page".to_string(), "1".to_string()))
+        .with_body(
+            json!({
+                "data": episodes,
+                "links": { "first": 1, "last": 1, "next": null, "prev": null },
+            })
+            .to_string(),
+        )
+        .create();
+
+    let ordered = client.series_watch_order(SERIES_ID).await?;
+
+    episodes_mock.assert();
+
+    assert_eq!(
+        ordered.iter().map(|e| e.id.0).collect::<Vec<_>>(),
+        vec![3, 11, 4, 12, 13, 2, 21, 1, 22, 5]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_language_coverage() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes", SERIES_ID);
+
+    let episodes: Vec<Episode> = vec![
+        (Some("Islands"), "ko"),
+        (Some("Jungles"), "en"),
+        (None, "ko"),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(id, (name, abbr))| Episode {
+        id: EpisodeID(id as u32),
+        episode_name: name.map(str::to_string),
+        language: EpisodeLanguage {
+            episode_name: abbr.to_string(),
+            overview: abbr.to_string(),
+        },
+        ..Default::default()
+    })
+    .collect();
+
+    let series = Series {
+        id: SeriesID(SERIES_ID),
+        series_name: Some("살아있는 지구 II".to_string()),
+        language: "ko".to_string(),
+        ..Default::default()
+    };
+
+    let episodes_mock = auth_mock(&client, GET, url.as_str())
+        .match_header("accept-language", "ko")
+        .match_query(UrlEncoded("page".to_string(), "1".to_string()))
+        .with_body(
+            json!({
+                "data": episodes,
+                "links": { "first": 1, "last": 1, "next": null, "prev": null },
+            })
+            .to_string(),
+        )
+        .create();
+
+    let series_mock = auth_mock(&client, GET, series_url().as_str())
+        .match_header("accept-language", "ko")
+        .with_body(json!({ "data": series }).to_string())
+        .create();
+
+    let coverage = client.series_language_coverage(SERIES_ID, "ko").await?;
+
+    episodes_mock.assert();
+    series_mock.assert();
+
+    assert_eq!(
+        coverage,
+        Coverage {
+            total_episodes: 3,
+            episodes_with_localized_name: 1,
+            series_name_localized: true,
+        }
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_localized_checked() -> Result<()> {
+    let mut client = authenticated_test_client().await;
+
+    let english = Series {
+        id: SeriesID(SERIES_ID),
+        series_name: Some("Planet Earth II".to_string()),
+        overview: Some("David Attenborough presents...".to_string()),
+        language: "en".to_string(),
+        ..Default::default()
+    };
+
+    let english_mock = auth_mock(&client, GET, series_url().as_str())
+        .match_header("accept-language", "en")
+        .with_body(json!({ "data": english }).to_string())
+        .expect(2)
+        .create();
+
+    let localized = client.series_localized_checked(SERIES_ID).await?;
+
+    assert!(!localized.name_is_fallback);
+    assert!(!localized.overview_is_fallback);
+
+    client.set_language_abbr("ko");
+
+    let korean = Series {
+        series_name: Some("살아있는 지구 II".to_string()),
+        language: "ko".to_string(),
+        ..english.clone()
+    };
+
+    let korean_mock = auth_mock(&client, GET, series_url().as_str())
+        .match_header("accept-language", "ko")
+        .with_body(json!({ "data": korean }).to_string())
+        .create();
+
+    let localized = client.series_localized_checked(SERIES_ID).await?;
+
+    english_mock.assert();
+    korean_mock.assert();
+
+    assert_eq!(localized.series, korean);
+    assert!(!localized.name_is_fallback);
+    assert!(localized.overview_is_fallback);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_original_language() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes", SERIES_ID);
+
+    let episodes: Vec<Episode> = vec!["en", "ko", "", "ko", "en", "ko"]
+        .into_iter()
+        .enumerate()
+        .map(|(id, abbr)| Episode {
+            id: EpisodeID(id as u32),
+            language: EpisodeLanguage {
+                episode_name: abbr.to_string(),
+                overview: "en".to_string(),
+            },
+            ..Default::default()
+        })
+        .collect();
+
+    let episodes_mock = auth_mock(&client, GET, url.as_str())
+        .match_query(UrlEncoded("page".to_string(), "1".to_string()))
+        .with_body(
+            json!({
+                "data": episodes,
+                "links": { "first": 1, "last": 2, "next": 2, "prev": null },
+            })
+            .to_string(),
+        )
+        .create();
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_body(
+            json!({ "data": Series { language: "ja".to_string(), ..Default::default() } })
+                .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    assert_eq!(
+        client.series_original_language(SERIES_ID).await?,
+        Some("ko".to_string())
+    );
+
+    episodes_mock.assert();
+
+    drop(episodes_mock);
+
+    let no_episodes_mock = auth_mock(&client, GET, url.as_str())
+        .match_query(UrlEncoded("page".to_string(), "1".to_string()))
+        .with_status(404)
+        .with_body(json!({ "Error": "No results for your query: map[]" }).to_string())
+        .create();
+
+    assert_eq!(
+        client.series_original_language(SERIES_ID).await?,
+        Some("ja".to_string())
+    );
+
+    no_episodes_mock.assert();
+    series_mock.assert();
+
+    let missing_url = format!("/series/{}/episodes", SERIES_ID + 1);
+
+    let missing_mock = auth_mock(&client, GET, missing_url.as_str())
+        .match_query(UrlEncoded("page".to_string(), "1".to_string()))
+        .with_status(404)
+        .with_body(json!({ "Error": "ID: 32168 not found" }).to_string())
+        .create();
+
+    match client
+        .series_original_language(SERIES_ID + 1)
+        .await
+        .unwrap_err()
+    {
+        Error::NotFound(_) => {}
+        e => panic!("Wrong error kind: expected NotFound, got {:?}", e),
+    }
+
+    missing_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_episodes_updated_since() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes", SERIES_ID);
+
+    let since = now_round_seconds() - Duration::days(7);
+
+    let episodes: Vec<Episode> = vec![
+        (1, Some(since - Duration::days(1))),
+        (2, Some(since + Duration::days(1))),
+        (3, None),
+        (4, Some(since)),
+        (5, Some(since + Duration::seconds(1))),
+    ]
+    .into_iter()
+    .map(|(id, last_updated)| Episode {
+        id: EpisodeID(id),
+        last_updated,
+        ..Default::default()
+    })
+    .collect();
+
+    let episodes_mock = auth_mock(&client, GET, url.as_str())
+        .match_query(UrlEncoded("page".to_string(), "1".to_string()))
+        .with_body(
+            serde_json::to_string(&json!({
+                "data": episodes,
+                "links": { "first": 1, "last": 1, "next": null, "prev": null },
+            }))
+            .unwrap(),
+        )
+        .create();
+
+    let updated = client
+        .series_episodes_updated_since(SERIES_ID, since)
+        .await?;
+
+    assert_eq!(
+        updated.iter().map(|e| e.id).collect::<Vec<_>>(),
+        vec![EpisodeID(2), EpisodeID(5)]
+    );
+
+    episodes_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_episodes_query() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes/query", SERIES_ID);
+
+    let (page, absolute_number, aired_season, aired_episode, dvd_season, dvd_episode, imdb_id) =
+        (3, 210, 5, 36, 6, 12, "tt12345678");
+    let first_aired = NaiveDate::from_ymd_opt(2016, 11, 6).unwrap();
+
+    let params = EpisodeQueryParams::new(SERIES_ID)
+        .page(page)
+        .absolute_number(absolute_number)
+        .aired_season(aired_season)
+        .aired_episode(aired_episode)
+        .dvd_season(dvd_season)
+        .dvd_episode(dvd_episode)
+        .imdb_id(imdb_id)
+        .first_aired(first_aired);
+
+    let episodes_query_mock = auth_lang_mock(&client, GET, url.as_str())
+        .match_query(AllOf(vec![
+            UrlEncoded("page".to_string(), page.to_string()),
+            UrlEncoded("absoluteNumber".to_string(), absolute_number.to_string()),
+            UrlEncoded("airedSeason".to_string(), aired_season.to_string()),
+            UrlEncoded("airedEpisode".to_string(), aired_episode.to_string()),
+            UrlEncoded("dvdSeason".to_string(), dvd_season.to_string()),
+            UrlEncoded("dvdEpisode".to_string(), dvd_episode.to_string()),
+            UrlEncoded("imdbId".to_string(), imdb_id.to_string()),
+            UrlEncoded("firstAired".to_string(), "2016-11-06".to_string()),
+        ]))
+        .create();
+
+    let _ = client.series_episodes_query(&params).await;
+
+    episodes_query_mock.assert();
+}
+
+#[tokio::test]
+async fn client_episode_by_dvd() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes/query", SERIES_ID);
+    let query = |season: &str, episode: &str| {
+        AllOf(vec![
+            UrlEncoded("page".to_string(), "1".to_string()),
+            UrlEncoded("dvdSeason".to_string(), season.to_string()),
+            UrlEncoded("dvdEpisode".to_string(), episode.to_string()),
+        ])
+    };
+
+    let found_mock = auth_mock(&client, GET, url.as_str())
+        .match_query(query("2", "3"))
+        .with_body(episode_page_body(&[EPISODE_ID], 1, 1, None, None))
+        .create();
+
+    let episode = client.episode_by_dvd(SERIES_ID, 2, 3).await?;
+
+    found_mock.assert();
+    assert_eq!(episode.map(|e| e.id), Some(EpisodeID(EPISODE_ID)));
+
+    let not_found_mock = auth_mock(&client, GET, url.as_str())
+        .match_query(query("9", "9"))
+        .with_status(404)
+        .with_body(json!({ "Error": "No results for your query: map[dvdSeason:9]" }).to_string())
+        .create();
+
+    assert_eq!(client.episode_by_dvd(SERIES_ID, 9, 9).await?, None);
+
+    not_found_mock.assert();
+
+    let missing_url = format!("/series/{}/episodes/query", SERIES_ID + 1);
+
+    let missing_mock = auth_mock(&client, GET, missing_url.as_str())
+        .match_query(query("2", "3"))
+        .with_status(404)
+        .with_body(json!({ "Error": "ID: 32168 not found" }).to_string())
+        .create();
+
+    match client
+        .episode_by_dvd(SERIES_ID + 1, 2, 3)
+        .await
+        .unwrap_err()
+    {
+        Error::NotFound(_) => {}
+        e => panic!("Wrong error kind: expected NotFound, got {:?}", e),
+    }
+
+    missing_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_episodes_summary() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes/summary", SERIES_ID);
+
+    let episodes_summary_mock = auth_mock(&client, GET, url.as_str()).create();
+
+    let _ = client.series_episodes_summary(SERIES_ID).await;
+
+    episodes_summary_mock.assert();
+}
+
+#[tokio::test]
+async fn client_series_summaries_many() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let summary_mock = |id: u32, aired_episodes: &str| {
+        let body = json!({
+            "data": {
+                "airedSeasons": ["1"],
+                "airedEpisodes": aired_episodes,
+                "dvdSeasons": [],
+                "dvdEpisodes": "0",
+            }
+        });
+
+        auth_mock(
+            &client,
+            GET,
+            format!("/series/{}/episodes/summary", id).as_str(),
+        )
+        .with_body(body.to_string())
+        .create()
+    };
+
+    let mocks = vec![summary_mock(1, "10"), summary_mock(3, "30")];
+
+    let not_found_mock = auth_mock(&client, GET, "/series/2/episodes/summary")
+        .with_status(404)
+        .create();
+
+    let summaries = client.series_summaries_many(vec![3, 2, 1]).await;
+
+    for mock in mocks {
+        mock.assert();
+    }
+    not_found_mock.assert();
+
+    let summaries: Vec<(SeriesID, Option<u32>)> = summaries
+        .into_iter()
+        .map(|(id, s)| (id, s.ok().map(|s| s.aired_episodes)))
+        .collect();
+
+    assert_eq!(
+        summaries,
+        vec![
+            (SeriesID(3), Some(30)),
+            (SeriesID(2), None),
+            (SeriesID(1), Some(10)),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_episode_total() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes/summary", SERIES_ID);
+
+    let body = json!({
+        "data": {
+            "airedSeasons": ["1", "2"],
+            "airedEpisodes": "23",
+            "dvdSeasons": [],
+            "dvdEpisodes": "0",
+        }
+    });
+
+    let episodes_summary_mock = auth_mock(&client, GET, url.as_str())
+        .with_body(body.to_string())
+        .create();
+
+    assert_eq!(client.series_episode_total(SERIES_ID).await?, 23);
+
+    episodes_summary_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_episodes_query_opt() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let query_url = format!("/series/{}/episodes/query", SERIES_ID);
+    let params = EpisodeQueryParams::new(SERIES_ID).aired_season(99);
+
+    let empty_mock = auth_lang_mock(&client, GET, query_url.as_str())
+        .match_query(Matcher::Any)
+        .with_status(404)
+        .with_body(json!({ "Error": "No results for your query: map[airedSeason:99]" }).to_string())
+        .create();
+
+    assert!(client.series_episodes_query_opt(&params).await?.is_none());
+
+    empty_mock.assert();
+
+    drop(empty_mock);
+
+    let missing_mock = auth_lang_mock(&client, GET, query_url.as_str())
+        .match_query(Matcher::Any)
+        .with_status(404)
+        .with_body(json!({ "Error": "ID: 32167 not found" }).to_string())
+        .create();
+
+    match client.series_episodes_query_opt(&params).await.unwrap_err() {
+        Error::NotFound(Some(message)) => assert_eq!(message, "ID: 32167 not found"),
+        e => panic!("Wrong error kind: expected NotFound, got {:?}", e),
+    }
+
+    missing_mock.assert();
+
+    drop(missing_mock);
+
+    let _page_mock = auth_lang_mock(&client, GET, query_url.as_str())
+        .match_query(Matcher::Any)
+        .with_body(
+            json!({
+                "data": [Episode { id: EpisodeID(EPISODE_ID), ..Default::default() }],
+                "links": { "first": 1, "last": 1, "next": null, "prev": null },
+            })
+            .to_string(),
+        )
+        .create();
+
+    let page = client.series_episodes_query_opt(&params).await?.unwrap();
+
+    assert_eq!(page.episodes[0].id, EpisodeID(EPISODE_ID));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_season() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let summary_url = format!("/series/{}/episodes/summary", SERIES_ID);
+    let query_url = format!("/series/{}/episodes/query", SERIES_ID);
+
+    let summary_mock = auth_mock(&client, GET, summary_url.as_str())
+        .with_body(
+            json!({
+                "data": {
+                    "airedSeasons": ["0", "1", "2"],
+                    "airedEpisodes": "3",
+                    "dvdSeasons": [],
+                    "dvdEpisodes": "0",
+                }
+            })
+            .to_string(),
+        )
+        .expect(3)
+        .create();
+
+    let page_body = |numbers: &[u32], next: Option<u16>| {
+        let episodes: Vec<Episode> = numbers
+            .iter()
+            .map(|n| Episode {
+                id: EpisodeID(*n),
+                aired_season: Some(1),
+                aired_episode_number: *n,
+                ..Default::default()
+            })
+            .collect();
+
+        json!({
+            "data": episodes,
+            "links": { "first": 1, "last": 2, "next": next, "prev": null },
+        })
+        .to_string()
+    };
+
+    let query = |season: &str, page: &str| {
+        AllOf(vec![
+            UrlEncoded("airedSeason".to_string(), season.to_string()),
+            UrlEncoded("page".to_string(), page.to_string()),
+        ])
+    };
+
+    let page_mocks = vec![
+        auth_lang_mock(&client, GET, query_url.as_str())
+            .match_query(query("1", "1"))
+            .with_body(page_body(&[3, 1], Some(2)))
+            .create(),
+        auth_lang_mock(&client, GET, query_url.as_str())
+            .match_query(query("1", "2"))
+            .with_body(page_body(&[2], None))
+            .create(),
+    ];
+
+    let season = client.season(SERIES_ID, 1).await?;
+
+    for mock in page_mocks {
+        mock.assert();
+    }
+
+    assert_eq!(season.number, 1);
+    assert_eq!(season.episode_count, 3);
+    assert_eq!(
+        season
+            .episodes
+            .iter()
+            .map(|e| e.aired_episode_number)
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    let empty_mock = auth_lang_mock(&client, GET, query_url.as_str())
+        .match_query(query("0", "1"))
+        .with_status(404)
+        .with_body(json!({ "Error": "No results for your query: map[]" }).to_string())
+        .create();
+
+    let specials = client.season(SERIES_ID, 0).await?;
+
+    empty_mock.assert();
+    assert_eq!(specials.episode_count, 0);
+
+    match client.season(SERIES_ID, 5).await.unwrap_err() {
+        Error::NotFound(_) => {}
+        e => wrong_error_kind(Error::NotFound(None), e),
+    }
+
+    summary_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_episode_with_neighbors() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let episode_url = format!("/episodes/{}", EPISODE_ID);
+    let query_url = format!("/series/{}/episodes/query", SERIES_ID);
+
+    let episode = |id: u32, season: u32, number: u32| Episode {
+        id: EpisodeID(id),
+        series_id: SeriesID(SERIES_ID),
+        aired_season: Some(season),
+        aired_episode_number: number,
+        ..Default::default()
+    };
+
+    let season_mock = |season: u32, episodes: Vec<Episode>| {
+        auth_lang_mock(&client, GET, query_url.as_str())
+            .match_query(AllOf(vec![
+                UrlEncoded("airedSeason".to_string(), season.to_string()),
+                UrlEncoded("page".to_string(), "1".to_string()),
+            ]))
+            .with_body(
+                json!({
+                    "data": episodes,
+                    "links": { "first": 1, "last": 1, "next": null, "prev": null },
+                })
+                .to_string(),
+            )
+            .create()
+    };
+
+    let current = episode(EPISODE_ID, 2, 1);
+
+    let episode_mock = auth_lang_mock(&client, GET, episode_url.as_str())
+        .with_body(json!({ "data": current }).to_string())
+        .create();
+
+    let season_mocks = vec![
+        season_mock(
+            1,
+            vec![episode(13, 1, 3), episode(11, 1, 1), episode(12, 1, 2)],
+        ),
+        season_mock(2, vec![episode(22, 2, 2), current.clone()]),
+    ];
+
+    let neighbors = client.episode_with_neighbors(EPISODE_ID).await?;
+
+    episode_mock.assert();
+    for mock in season_mocks {
+        mock.assert();
+    }
+
+    assert_eq!(neighbors.current, current);
+    assert_eq!(neighbors.previous.unwrap().id, EpisodeID(13));
+    assert_eq!(neighbors.next.unwrap().id, EpisodeID(22));
+
+    drop(episode_mock);
+
+    let current = episode(EPISODE_ID, 2, 2);
+
+    let _episode_mock = auth_lang_mock(&client, GET, episode_url.as_str())
+        .with_body(json!({ "data": current }).to_string())
+        .create();
+
+    let _season_mock = season_mock(2, vec![episode(21, 2, 1), current.clone()]);
+
+    let last_season_mock = auth_lang_mock(&client, GET, query_url.as_str())
+        .match_query(UrlEncoded("airedSeason".to_string(), "3".to_string()))
+        .with_status(404)
+        .with_body(json!({ "Error": "No results for your query: map[]" }).to_string())
+        .create();
+
+    let neighbors = client.episode_with_neighbors(EPISODE_ID).await?;
+
+    last_season_mock.assert();
+
+    assert_eq!(neighbors.previous.unwrap().id, EpisodeID(21));
+    assert_eq!(neighbors.next, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_is_miniseries() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let summary_url = format!("/series/{}/episodes/summary", SERIES_ID);
+
+    let cases = vec![
+        (SeriesStatus::Ended, vec!["0", "1"], 6, true),
+        (SeriesStatus::Ended, vec!["1"], 13, false),
+        (SeriesStatus::Ended, vec!["1", "2"], 6, false),
+        (SeriesStatus::Continuing, vec!["1"], 6, false),
+    ];
+
+    for (status, seasons, episodes, expected) in cases {
+        let series = Series {
+            id: SeriesID(SERIES_ID),
+            status,
+            ..Default::default()
+        };
+
+        let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+            .with_body(serde_json::to_string(&json!({ "data": series })).unwrap())
+            .create();
+
+        let summary_mock = auth_mock(&client, GET, summary_url.as_str())
+            .with_body(
+                json!({
+                    "data": {
+                        "airedSeasons": seasons,
+                        "airedEpisodes": episodes.to_string(),
+                        "dvdSeasons": [],
+                        "dvdEpisodes": "0",
+                    }
+                })
+                .to_string(),
+            )
+            .create();
+
+        assert_eq!(client.series_is_miniseries(SERIES_ID, 12).await?, expected);
+
+        series_mock.assert();
+        summary_mock.assert();
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_filter() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/filter", SERIES_ID);
+
+    let params = SeriesFilterKeys::new()
+        .network_id()
+        .airs_time()
+        .site_rating()
+        .series_name()
+        .first_aired()
+        .runtime()
+        .overview()
+        .banner()
+        .genre()
+        .airs_day_of_week()
+        .imdb_id()
+        .added_by()
+        .site_rating_count()
+        .id()
+        .status()
+        .network()
+        .rating()
+        .zap2it_id()
+        .added()
+        .slug()
+        .aliases()
+        .season()
+        .poster()
+        .fanart()
+        .language()
+        .last_updated();
+
+    assert!(params.is_at_full_capacity());
+
+    let series_filter_mock = auth_lang_mock(&client, GET, url.as_str())
+        .match_query(UrlEncoded("keys".to_string(), params.keys_query.clone()))
+        .create();
+
+    let _ = client.series_filter(SERIES_ID, &params).await;
+
+    series_filter_mock.assert();
+}
+
+#[tokio::test]
+async fn client_filter_airing() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let filter_mock = |id: u32, status: &str| {
+        auth_lang_mock(&client, GET, format!("/series/{}/filter", id).as_str())
+            .match_query(UrlEncoded(
+                "keys".to_string(),
+                "id,status,seriesName".to_string(),
+            ))
+            .with_body(
+                json!({ "data": { "id": id, "status": status, "seriesName": "Test" } }).to_string(),
+            )
+            .create()
+    };
+
+    let mocks = vec![
+        filter_mock(1, "Continuing"),
+        filter_mock(2, "Ended"),
+        filter_mock(3, ""),
+        filter_mock(4, "Continuing"),
+    ];
+
+    let airing = client
+        .filter_airing(&[SeriesID(4), SeriesID(3), SeriesID(2), SeriesID(1)])
+        .await?;
+
+    for mock in mocks {
+        mock.assert();
+    }
+
+    assert_eq!(
+        airing.iter().map(|s| s.id).collect::<Vec<_>>(),
+        vec![Some(SeriesID(4)), Some(SeriesID(1))]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_poster() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/filter", SERIES_ID);
+
+    let poster_mock = auth_lang_mock(&client, GET, url.as_str())
+        .match_query(UrlEncoded("keys".to_string(), "poster".to_string()))
+        .with_body(json!({ "data": { "poster": "posters/poster.jpg" } }).to_string())
+        .create();
+
+    let poster = client.series_poster(SERIES_ID).await?;
+
+    poster_mock.assert();
+
+    assert_eq!(
+        poster,
+        Some(Url::parse(
+            "https://www.thetvdb.com/banners/posters/poster.jpg"
+        )?)
+    );
+
+    let no_poster_mock = auth_lang_mock(&client, GET, url.as_str())
+        .match_query(UrlEncoded("keys".to_string(), "poster".to_string()))
+        .with_body(json!({ "data": { "poster": "" } }).to_string())
+        .create();
+
+    assert_eq!(client.series_poster(SERIES_ID).await?, None);
+
+    no_poster_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_fetch_image() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let image_mock = mock(GET, "/banners/posters/poster.jpg")
+        .with_body("poster bytes")
+        .create();
+
+    let url = Url::parse(&mockito::server_url())?.join("/banners/posters/poster.jpg")?;
+
+    let bytes = client.fetch_image(&url).await?;
+
+    image_mock.assert();
+
+    assert_eq!(&bytes[..], b"poster bytes");
+
+    let not_found_mock = mock(GET, "/banners/posters/missing.jpg")
+        .with_status(404)
+        .create();
+
+    let url = url.join("missing.jpg")?;
+
+    assert_eq!(
+        client.fetch_image(&url).await.unwrap_err().to_string(),
+        Error::NotFound(None).to_string()
+    );
+
+    not_found_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_download_posters() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let no_poster_mock = auth_lang_mock(&client, GET, "/series/1/filter")
+        .match_query(UrlEncoded("keys".to_string(), "poster".to_string()))
+        .with_body(json!({ "data": { "poster": null } }).to_string())
+        .create();
+
+    let not_found_mock = auth_lang_mock(&client, GET, "/series/2/filter")
+        .match_query(UrlEncoded("keys".to_string(), "poster".to_string()))
+        .with_status(404)
+        .create();
+
+    let ids = vec![SeriesID(1), SeriesID(2)];
+
+    let posters: Vec<(SeriesID, Result<_>)> = client.download_posters(&ids).collect().await;
+
+    no_poster_mock.assert();
+    not_found_mock.assert();
+
+    let posters: Vec<(SeriesID, String)> = posters
+        .into_iter()
+        .map(|(id, p)| (id, p.unwrap_err().to_string()))
+        .collect();
+
+    assert_eq!(
+        posters,
+        vec![
+            (SeriesID(1), Error::MissingImage.to_string()),
+            (SeriesID(2), Error::NotFound(None).to_string()),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_images() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/images", SERIES_ID);
+
+    let images_mock = auth_lang_mock(&client, GET, url.as_str()).create();
+
+    let _ = client.series_images(SERIES_ID).await;
+
+    images_mock.assert();
+}
+
+#[tokio::test]
+async fn client_series_images_with_primary() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let summary_url = format!("/series/{}/images", SERIES_ID);
+    let query_url = format!("/series/{}/images/query", SERIES_ID);
+
+    let summary =
+        json!({ "fanart": 3, "poster": 3, "season": null, "seasonwide": null, "series": 1 });
+
+    let posters: Vec<_> = vec![(1, 7.5, 4), (2, 9.0, 2), (3, 9.0, 10)]
+        .into_iter()
+        .map(|(id, average, count)| {
+            json!({
+                "id": id,
+                "keyType": "poster",
+                "subKey": "",
+                "fileName": format!("posters/{}.jpg", id),
+                "languageId": 7,
+                "language": "en",
+                "resolution": "680x1000",
+                "ratingsInfo": { "average": average, "count": count },
+                "thumbnail": ""
+            })
+        })
+        .collect();
+
+    let summary_mock = auth_lang_mock(&client, GET, summary_url.as_str())
+        .with_body(json!({ "data": summary }).to_string())
+        .expect(2)
+        .create();
+
+    let posters_mock = auth_lang_mock(&client, GET, query_url.as_str())
+        .match_query(UrlEncoded("keyType".to_string(), "poster".to_string()))
+        .with_body(json!({ "data": posters }).to_string())
+        .create();
+
+    let (images, poster) = client.series_images_with_primary(SERIES_ID).await?;
+
+    posters_mock.assert();
+
+    assert_eq!(images.poster, Some(3));
+    assert_eq!(poster.map(|p| p.id), Some(3));
+
+    drop(posters_mock);
+
+    let no_posters_mock = auth_lang_mock(&client, GET, query_url.as_str())
+        .match_query(UrlEncoded("keyType".to_string(), "poster".to_string()))
+        .with_status(404)
+        .with_body(json!({ "Error": "No results for your query: map[]" }).to_string())
+        .create();
+
+    let (_, poster) = client.series_images_with_primary(SERIES_ID).await?;
+
+    assert_eq!(poster, None);
+
+    summary_mock.assert();
+    no_posters_mock.assert();
+
+    let missing_summary_url = format!("/series/{}/images", SERIES_ID + 1);
+    let missing_query_url = format!("/series/{}/images/query", SERIES_ID + 1);
+
+    let missing_body = json!({ "Error": "ID: 32168 not found" }).to_string();
+
+    let _missing_summary_mock = auth_lang_mock(&client, GET, missing_summary_url.as_str())
+        .with_status(404)
+        .with_body(&missing_body)
+        .create();
+
+    let _missing_posters_mock = auth_lang_mock(&client, GET, missing_query_url.as_str())
+        .match_query(UrlEncoded("keyType".to_string(), "poster".to_string()))
+        .with_status(404)
+        .with_body(&missing_body)
+        .create();
+
+    match client
+        .series_images_with_primary(SERIES_ID + 1)
+        .await
+        .unwrap_err()
+    {
+        Error::NotFound(_) => {}
+        e => panic!("Wrong error kind: expected NotFound, got {:?}", e),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_images_query() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/images/query", SERIES_ID);
+
+    let (key_type, resolution, sub_key) = ("test_key", "123x321", "test_sub_key");
+
+    let images_query_mock = auth_lang_mock(&client, GET, url.as_str())
+        .match_query(AllOf(vec![
+            UrlEncoded("keyType".to_string(), key_type.to_string()),
+            UrlEncoded("resolution".to_string(), resolution.to_string()),
+            UrlEncoded("subKey".to_string(), sub_key.to_string()),
+        ]))
+        .create();
+
+    let params = ImageQueryParams::with_key_type(key_type)
+        .resolution(resolution)
+        .sub_key(sub_key);
+
+    let _ = client.series_images_query(SERIES_ID, &params).await;
+
+    images_query_mock.assert();
+}
+
+#[tokio::test]
+async fn client_series_images_query_in_language() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/images/query", SERIES_ID);
+    let abbr = "de";
+
+    let images: Vec<Image> = vec![(1, "de"), (2, "en"), (3, "de")]
+        .into_iter()
+        .map(|(id, language)| Image {
+            id,
+            language: language.to_string(),
+            ..Default::default()
+        })
+        .collect();
+
+    let images_mock = auth_mock(&client, GET, url.as_str())
+        .match_header("accept-language", abbr)
+        .match_query(UrlEncoded("keyType".to_string(), "poster".to_string()))
+        .with_body(serde_json::to_string(&json!({ "data": images })).unwrap())
+        .create();
+
+    let params = ImageQueryParams::with_key_type("poster");
+
+    let res = client
+        .series_images_query_in_language(SERIES_ID, &params, abbr)
+        .await?;
+
+    assert_eq!(res.iter().map(|i| i.id).collect::<Vec<_>>(), vec![1, 3]);
+
+    images_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_episodes_query_params() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/episodes/query/params", SERIES_ID);
+
+    let query_params_mock = auth_mock(&client, GET, url.as_str())
+        .with_body(json!({ "data": ["absoluteNumber", "airedSeason", "airedEpisode"] }).to_string())
+        .create();
+
+    let keys = client.series_episodes_query_params(SERIES_ID).await?;
+
+    query_params_mock.assert();
+
+    assert_eq!(keys, vec!["absoluteNumber", "airedSeason", "airedEpisode"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_images_query_params() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/images/query/params", SERIES_ID);
+
+    let images_query_params_mock = auth_mock(&client, GET, url.as_str()).create();
+
+    let _ = client.series_images_query_params(SERIES_ID).await;
+
+    images_query_params_mock.assert();
+}
+
+#[tokio::test]
+async fn client_series_image_languages() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/series/{}/images/query/params", SERIES_ID);
+
+    let body = json!({
+        "data": [
+            { "keyType": "fanart", "languageId": "7", "resolution": [], "subKey": [] },
+            { "keyType": "poster", "languageId": "", "resolution": [], "subKey": [] },
+            { "keyType": "season", "languageId": "14", "resolution": [], "subKey": [] },
+            { "keyType": "series", "languageId": "7", "resolution": [], "subKey": [] },
+            { "keyType": "seasonwide", "languageId": "none", "resolution": [], "subKey": [] },
+        ]
+    });
+
+    let params_mock = auth_mock(&client, GET, url.as_str())
+        .with_body(body.to_string())
+        .create();
+
+    let ids = client.series_image_languages(SERIES_ID).await?;
+
+    assert_eq!(ids, vec![LanguageID(7), LanguageID(14)]);
+
+    params_mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_series_images_all_resolutions() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let params_url = format!("/series/{}/images/query/params", SERIES_ID);
+    let query_url = format!("/series/{}/images/query", SERIES_ID);
+
+    let params_body = json!({
+        "data": [
+            { "keyType": "fanart", "languageId": "7", "resolution": ["1280x720", "1920x1080"], "subKey": [] },
+            { "keyType": "fanart", "languageId": "14", "resolution": ["1920x1080"], "subKey": [] },
+            { "keyType": "poster", "languageId": "7", "resolution": ["680x1000"], "subKey": [] },
+        ]
+    });
+
+    let image = |id: u32, resolution: &str| {
+        json!({
+            "id": id,
+            "keyType": "fanart",
+            "subKey": "",
+            "fileName": format!("fanart/{}.jpg", id),
+            "languageId": 7,
+            "language": "en",
+            "resolution": resolution,
+            "ratingsInfo": { "average": 0.0, "count": 0 },
+            "thumbnail": ""
+        })
+    };
+
+    let params_mock = auth_mock(&client, GET, params_url.as_str())
+        .with_body(params_body.to_string())
+        .create();
+
+    let mut query_mocks = Vec::new();
+    for (resolution, ids) in &[("1280x720", vec![1, 2]), ("1920x1080", vec![2, 3])] {
+        let images: Vec<_> = ids.iter().map(|id| image(*id, resolution)).collect();
+
+        query_mocks.push(
+            auth_lang_mock(&client, GET, query_url.as_str())
+                .match_query(AllOf(vec![
+                    UrlEncoded("keyType".to_string(), "fanart".to_string()),
+                    UrlEncoded("resolution".to_string(), resolution.to_string()),
+                ]))
+                .with_body(json!({ "data": images }).to_string())
+                .create(),
+        );
+    }
+
+    let images = client
+        .series_images_all_resolutions(SERIES_ID, "fanart")
+        .await?;
+
+    assert_eq!(
+        images.iter().map(|i| i.id).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    params_mock.assert();
+    for mock in query_mocks {
+        mock.assert();
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_episode() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/episodes/{}", EPISODE_ID);
+
+    let episode_mock = auth_lang_mock(&client, GET, url.as_str()).create();
+
+    let _ = client.episode(EPISODE_ID).await;
+
+    episode_mock.assert();
+}
+
+#[tokio::test]
+async fn client_episode_last_modified() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/episodes/{}", EPISODE_ID);
+
+    let last_modified_mock = auth_mock(&client, HEAD, url.as_str()).create();
+
+    let _ = client.episode_last_modified(EPISODE_ID).await;
+
+    last_modified_mock.assert();
+}
+
+#[tokio::test]
+async fn client_episode_if_modified_since() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/episodes/{}", EPISODE_ID);
+    let last_modified = now_round_seconds() - Duration::days(2);
+
+    let cases = vec![
+        (last_modified + Duration::days(1), 0),
+        (last_modified - Duration::days(1), 1),
+    ];
+
+    for (since, expected_gets) in cases {
+        let head_mock = auth_mock(&client, HEAD, url.as_str())
+            .with_header("Last-Modified", &last_modified.to_rfc2822())
+            .create();
+
+        let get_mock = auth_lang_mock(&client, GET, url.as_str())
+            .expect(expected_gets)
+            .create();
+
+        let res = client.episode_if_modified_since(EPISODE_ID, since).await;
+
+        if expected_gets == 0 {
+            assert!(res?.is_none());
+        }
+
+        head_mock.assert();
+        get_mock.assert();
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_language_maps() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let languages = json!({
+        "data": [
+            { "id": 7, "abbreviation": "en", "name": "English", "englishName": "English" },
+            { "id": 14, "abbreviation": "de", "name": "Deutsch", "englishName": "German" },
+        ]
+    });
+
+    let languages_mock = auth_mock(&client, GET, "/languages")
+        .with_body(languages.to_string())
+        .create();
+
+    let by_id = client.language_map().await?;
+    let by_abbr = client.language_abbr_map().await?;
+
+    // languages are requested only once
+    languages_mock.assert();
+
+    assert_eq!(by_id.len(), 2);
+    assert_eq!(by_id[&LanguageID(14)].abbreviation, "de");
+
+    assert_eq!(by_abbr.len(), 2);
+    assert_eq!(by_abbr["en"].id, LanguageID(7));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_languages() {
+    let client = authenticated_test_client().await;
+
+    let languages_mock = auth_mock(&client, GET, "/languages").create();
+
+    let _ = client.languages().await;
+
+    languages_mock.assert();
+}
+
+#[tokio::test]
+async fn client_language() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/languages/{}", LANGUAGE_ID);
+
+    let language_mock = auth_mock(&client, GET, url.as_str()).create();
+
+    let _ = client.language(LANGUAGE_ID).await;
+
+    language_mock.assert();
+}
+
+#[tokio::test]
+async fn client_updated() {
+    let client = authenticated_test_client().await;
+
+    let (from, to) = (
+        Utc::now() - Duration::days(9),
+        Utc::now() - Duration::days(4),
+    );
+
+    let updated_mock = auth_lang_mock(&client, GET, "/updated/query")
+        .match_query(AllOf(vec![
+            UrlEncoded("fromTime".to_string(), from.timestamp().to_string()),
+            UrlEncoded("toTime".to_string(), to.timestamp().to_string()),
+        ]))
+        .create();
+
+    let params = UpdatedParams::with_to_time(from, to);
+
+    let _ = client.updated(&params).await;
+
+    updated_mock.assert();
+}
+
+#[tokio::test]
+async fn client_movie() {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/movies/{}", MOVIE_ID);
+
+    let movie_mock = auth_lang_mock(&client, GET, url.as_str()).create();
+
+    let _ = client.movie(MOVIE_ID).await;
+
+    movie_mock.assert();
+}
+
+#[tokio::test]
+async fn client_movie_raw() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let url = format!("/movies/{}", MOVIE_ID);
+
+    let body = json!({
+        "data": {
+            "id": MOVIE_ID,
+            "runtime": 120,
+            "budget": "1000000",
+        }
+    });
+
+    let movie_mock = auth_lang_mock(&client, GET, url.as_str())
+        .with_body(body.to_string())
+        .create();
+
+    let movie = client.movie_raw(MOVIE_ID).await?;
+
+    movie_mock.assert();
+
+    assert_eq!(movie, body["data"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_with_raw() -> Result<()> {
+    let client = authenticated_test_client().await;
+
+    let series = Series {
+        id: SERIES_ID.into(),
+        ..Default::default()
+    };
+    let mut series_data = serde_json::to_value(&series)?;
+    series_data["banner"] = json!("");
+    series_data["notInSeries"] = json!(true);
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_body(json!({ "data": series_data }).to_string())
+        .create();
+
+    assert_eq!(
+        client.series_with_raw(SERIES_ID).await?,
+        (series, series_data)
+    );
+
+    series_mock.assert();
+
+    let episode = Episode {
+        id: EPISODE_ID.into(),
+        ..Default::default()
+    };
+    let mut episode_data = serde_json::to_value(&episode)?;
+    episode_data["filename"] = json!("");
+    episode_data["notInEpisode"] = json!(true);
+
+    let episode_url = format!("/episodes/{}", EPISODE_ID);
+    let episode_mock = auth_lang_mock(&client, GET, episode_url.as_str())
+        .with_body(json!({ "data": episode_data }).to_string())
+        .create();
+
+    assert_eq!(
+        client.episode_with_raw(EPISODE_ID).await?,
+        (episode, episode_data)
+    );
+
+    episode_mock.assert();
+
+    let movie_data = json!({
+        "id": MOVIE_ID,
+        "url": "movie_url",
+        "runtime": 120,
+        "genres": [{ "url": "/genres/drama", "name": "Drama", "id": 2 }],
+        "translations": [],
+        "release_dates": [],
+        "artworks": [],
+        "trailers": [],
+        "remoteids": [],
+        "people": {},
+        "budget": "1000000",
+    });
+
+    let movie_url = format!("/movies/{}", MOVIE_ID);
+    let movie_mock = auth_lang_mock(&client, GET, movie_url.as_str())
+        .with_body(json!({ "data": movie_data }).to_string())
+        .create();
+
+    let (movie, raw) = client.movie_with_raw(MOVIE_ID).await?;
+
+    movie_mock.assert();
 
-        let _ = client.search(search_by).await;
+    assert_eq!(movie.id, MovieID(MOVIE_ID));
+    assert_eq!(raw, movie_data);
 
-        mock.assert();
-    }
+    let series = Series {
+        genre: vec!["Drama".to_string()],
+        ..Default::default()
+    };
+
+    assert!(client.resolve_series_genres(&series)[0].is_some());
+
+    Ok(())
 }
 
 #[tokio::test]
-async fn client_series() {
+async fn client_get_into() -> Result<()> {
     let client = authenticated_test_client().await;
 
-    let series_mock = auth_lang_mock(&client, GET, series_url().as_str()).create();
+    let url = format!("/series/{}/episodes/query/params", SERIES_ID);
 
-    let _ = client.series(SERIES_ID).await;
+    let params_mock = auth_mock(&client, GET, url.as_str())
+        .match_header("accept-language", Matcher::Missing)
+        .with_body(json!({ "data": ["airedSeason", "airedEpisode"] }).to_string())
+        .create();
+
+    let params: Vec<String> = client.get_into(&url, false).await?;
+
+    params_mock.assert();
+
+    assert_eq!(params, vec!["airedSeason", "airedEpisode"]);
+
+    let series_mock = auth_lang_mock(&client, GET, series_url().as_str())
+        .with_body(json!({ "data": { "id": SERIES_ID } }).to_string())
+        .create();
+
+    let series: serde_json::Value = client.get_into(&series_url(), true).await?;
 
     series_mock.assert();
-}
 
-#[tokio::test]
-async fn client_series_last_modified() {
-    let client = authenticated_test_client().await;
+    assert_eq!(series["id"], SERIES_ID);
 
-    let url = format!("/series/{}", SERIES_ID);
+    let not_found_mock = mock(GET, "/unknown").with_status(404).create();
 
-    let last_modified_mock = auth_mock(&client, HEAD, url.as_str()).create();
+    match client
+        .get_into::<serde_json::Value>("/unknown", false)
+        .await
+        .unwrap_err()
+    {
+        Error::NotFound(_) => {}
+        e => wrong_error_kind(Error::NotFound(None), e),
+    }
 
-    let _ = client.series_last_modified(SERIES_ID).await;
+    not_found_mock.assert();
 
-    last_modified_mock.assert();
+    Ok(())
 }
 
 #[tokio::test]
-async fn client_series_actors() {
+async fn client_get_into_absolute_url() {
     let client = authenticated_test_client().await;
 
-    let url = format!("/series/{}/actors", SERIES_ID);
+    let foreign_mock = mock(GET, "/x").expect(0).create();
 
-    let actors_mock = auth_mock(&client, GET, url.as_str()).create();
+    let absolute = format!("{}/x", mockito::server_url());
 
-    let _ = client.series_actors(SERIES_ID).await;
+    match client
+        .get_into::<serde_json::Value>(&absolute, false)
+        .await
+        .unwrap_err()
+    {
+        Error::InvalidUrl(_) => {}
+        e => panic!("Wrong error kind: expected InvalidUrl, got {:?}", e),
+    }
 
-    actors_mock.assert();
+    foreign_mock.assert();
 }
 
 #[tokio::test]
-async fn client_series_episodes() {
+async fn client_get_into_scheme_relative_url() {
     let client = authenticated_test_client().await;
 
-    let url = format!("/series/{}/episodes", SERIES_ID);
-
-    let cases = &[
-        EpisodeParams::new(SERIES_ID),
-        EpisodeParams::with_page(SERIES_ID, 32),
-    ];
-
-    for params in cases {
-        let mock = auth_mock(&client, GET, url.as_str())
-            .match_query(UrlEncoded("page".to_string(), params.page.to_string()))
-            .create();
+    let foreign_mock = mock(GET, "/x").expect(0).create();
 
-        let _ = client.series_episodes(&params).await;
+    let scheme_relative = format!("//{}/x", mockito::server_address());
 
-        mock.assert();
+    match client
+        .get_into::<serde_json::Value>(&scheme_relative, false)
+        .await
+        .unwrap_err()
+    {
+        Error::InvalidUrl(_) => {}
+        e => panic!("Wrong error kind: expected InvalidUrl, got {:?}", e),
     }
+
+    foreign_mock.assert();
 }
 
 #[tokio::test]
-async fn client_series_episodes_query() {
+async fn client_resolve_series_genres() -> Result<()> {
     let client = authenticated_test_client().await;
 
-    let url = format!("/series/{}/episodes/query", SERIES_ID);
-
-    let (page, absolute_number, aired_season, aired_episode, dvd_season, dvd_episode, imdb_id) =
-        (3, 210, 5, 36, 6, 12, "tt12345678");
+    let url = format!("/movies/{}", MOVIE_ID);
 
-    let params = EpisodeQueryParams::new(SERIES_ID)
-        .page(page)
-        .absolute_number(absolute_number)
-        .aired_season(aired_season)
-        .aired_episode(aired_episode)
-        .dvd_season(dvd_season)
-        .dvd_episode(dvd_episode)
-        .imdb_id(imdb_id);
+    let body = json!({
+        "data": {
+            "id": MOVIE_ID,
+            "url": "movie_url",
+            "runtime": 120,
+            "genres": [
+                { "url": "/genres/action", "name": "Action", "id": 1 },
+                { "url": "/genres/drama", "name": "Drama", "id": 2 },
+            ],
+            "translations": [],
+            "release_dates": [],
+            "artworks": [],
+            "trailers": [],
+            "remoteids": [],
+            "people": {},
+        }
+    });
 
-    let episodes_query_mock = auth_lang_mock(&client, GET, url.as_str())
-        .match_query(AllOf(vec![
-            UrlEncoded("page".to_string(), page.to_string()),
-            UrlEncoded("absoluteNumber".to_string(), absolute_number.to_string()),
-            UrlEncoded("airedSeason".to_string(), aired_season.to_string()),
-            UrlEncoded("airedEpisode".to_string(), aired_episode.to_string()),
-            UrlEncoded("dvdSeason".to_string(), dvd_season.to_string()),
-            UrlEncoded("dvdEpisode".to_string(), dvd_episode.to_string()),
-            UrlEncoded("imdbId".to_string(), imdb_id.to_string()),
-        ]))
+    let movie_mock = auth_lang_mock(&client, GET, url.as_str())
+        .with_body(body.to_string())
         .create();
 
-    let _ = client.series_episodes_query(&params).await;
+    let series = Series {
+        genre: vec!["drama".to_string(), "Comedy".to_string()],
+        ..Default::default()
+    };
 
-    episodes_query_mock.assert();
+    assert_eq!(client.resolve_series_genres(&series), vec![None, None]);
+
+    client.movie(MOVIE_ID).await?;
+
+    movie_mock.assert();
+
+    let genres = client.resolve_series_genres(&series);
+
+    assert_eq!(genres.len(), 2);
+    assert_eq!(genres[0].as_ref().map(|g| g.id), Some(2));
+    assert_eq!(genres[1], None);
+
+    Ok(())
 }
 
 #[tokio::test]
-async fn client_episodes_summary() {
+async fn client_resolve_series_genres_cold_cache() {
     let client = authenticated_test_client().await;
 
-    let url = format!("/series/{}/episodes/summary", SERIES_ID);
+    let movie_mock = auth_mock(&client, GET, Matcher::Regex("^/movies/".to_string()))
+        .expect(0)
+        .create();
 
-    let episodes_summary_mock = auth_mock(&client, GET, url.as_str()).create();
+    let series = Series {
+        genre: vec!["Drama".to_string(), "Action".to_string()],
+        ..Default::default()
+    };
 
-    let _ = client.series_episodes_summary(SERIES_ID).await;
+    assert_eq!(client.resolve_series_genres(&series), vec![None, None]);
 
-    episodes_summary_mock.assert();
+    movie_mock.assert();
 }
 
 #[tokio::test]
-async fn client_series_filter() {
+async fn client_movie_updates() -> Result<()> {
     let client = authenticated_test_client().await;
 
-    let url = format!("/series/{}/filter", SERIES_ID);
+    let since = Utc::now();
 
-    let params = SeriesFilterKeys::new()
-        .network_id()
-        .airs_time()
-        .site_rating()
-        .series_name()
-        .first_aired()
-        .runtime()
-        .overview()
-        .banner()
-        .genre()
-        .airs_day_of_week()
-        .imdb_id()
-        .added_by()
-        .site_rating_count()
-        .id()
-        .status()
-        .network()
-        .rating()
-        .zap2it_id()
-        .added()
-        .slug()
-        .aliases()
-        .season()
-        .poster()
-        .fanart()
-        .language()
-        .last_updated();
+    let movie_updates_mock = auth_mock(&client, GET, "/movieupdates")
+        .match_query(UrlEncoded(
+            "since".to_string(),
+            since.timestamp().to_string(),
+        ))
+        .with_body(r#"{"movies": [12, 345]}"#)
+        .create();
 
-    assert!(params.is_at_full_capacity());
+    let updates = client.movie_updates(since).await?;
 
-    let series_filter_mock = auth_lang_mock(&client, GET, url.as_str())
-        .match_query(UrlEncoded("keys".to_string(), params.keys_query.clone()))
-        .create();
+    assert_eq!(updates.movies, vec![MovieID(12), MovieID(345)]);
 
-    let _ = client.series_filter(SERIES_ID, &params).await;
+    movie_updates_mock.assert();
 
-    series_filter_mock.assert();
+    Ok(())
 }
 
 #[tokio::test]
-async fn client_series_images() {
+async fn client_changes_since() -> Result<()> {
     let client = authenticated_test_client().await;
 
-    let url = format!("/series/{}/images", SERIES_ID);
+    let since = now_round_seconds() - Duration::days(10);
+    let second_chunk = since + Duration::days(UPDATED_MAX_DAYS);
 
-    let images_mock = auth_lang_mock(&client, GET, url.as_str()).create();
+    let first_mock = auth_lang_mock(&client, GET, "/updated/query")
+        .match_query(AllOf(vec![
+            UrlEncoded("fromTime".to_string(), since.timestamp().to_string()),
+            UrlEncoded("toTime".to_string(), second_chunk.timestamp().to_string()),
+        ]))
+        .with_body(
+            json!({
+                "data": [
+                    { "id": 5, "lastUpdated": since.timestamp() + 10 },
+                    { "id": 2, "lastUpdated": since.timestamp() + 20 },
+                    { "id": 5, "lastUpdated": since.timestamp() + 30 },
+                ]
+            })
+            .to_string(),
+        )
+        .create();
 
-    let _ = client.series_images(SERIES_ID).await;
+    let second_mock = auth_lang_mock(&client, GET, "/updated/query")
+        .match_query(UrlEncoded(
+            "fromTime".to_string(),
+            second_chunk.timestamp().to_string(),
+        ))
+        .with_status(404)
+        .with_body(json!({ "Error": "No results for your query: map[]" }).to_string())
+        .create();
 
-    images_mock.assert();
+    let movie_updates_mock = auth_mock(&client, GET, "/movieupdates")
+        .match_query(UrlEncoded(
+            "since".to_string(),
+            since.timestamp().to_string(),
+        ))
+        .with_body(r#"{"movies": [12, 3, 12]}"#)
+        .create();
+
+    let changes = client.changes_since(since).await?;
+
+    first_mock.assert();
+    second_mock.assert();
+    movie_updates_mock.assert();
+
+    assert_eq!(changes.series, vec![SeriesID(2), SeriesID(5)]);
+    assert_eq!(changes.movies, vec![MovieID(3), MovieID(12)]);
+
+    Ok(())
 }
 
 #[tokio::test]
-async fn client_series_images_query() {
+async fn client_updated_map() -> Result<()> {
     let client = authenticated_test_client().await;
 
-    let url = format!("/series/{}/images/query", SERIES_ID);
+    let from = now_round_seconds() - Duration::days(10);
+    let second_chunk = from + Duration::days(UPDATED_MAX_DAYS);
+    let to = from + Duration::days(9);
 
-    let (key_type, resolution, sub_key) = ("test_key", "123x321", "test_sub_key");
+    let first_mock = auth_lang_mock(&client, GET, "/updated/query")
+        .match_query(AllOf(vec![
+            UrlEncoded("fromTime".to_string(), from.timestamp().to_string()),
+            UrlEncoded("toTime".to_string(), second_chunk.timestamp().to_string()),
+        ]))
+        .with_body(
+            json!({
+                "data": [
+                    { "id": 5, "lastUpdated": from.timestamp() + 30 },
+                    { "id": 2, "lastUpdated": from.timestamp() + 20 },
+                    { "id": 5, "lastUpdated": from.timestamp() + 10 },
+                ]
+            })
+            .to_string(),
+        )
+        .create();
 
-    let images_query_mock = auth_lang_mock(&client, GET, url.as_str())
+    let second_mock = auth_lang_mock(&client, GET, "/updated/query")
         .match_query(AllOf(vec![
-            UrlEncoded("keyType".to_string(), key_type.to_string()),
-            UrlEncoded("resolution".to_string(), resolution.to_string()),
-            UrlEncoded("subKey".to_string(), sub_key.to_string()),
+            UrlEncoded("fromTime".to_string(), second_chunk.timestamp().to_string()),
+            UrlEncoded("toTime".to_string(), to.timestamp().to_string()),
         ]))
+        .with_body(
+            json!({ "data": [{ "id": 2, "lastUpdated": second_chunk.timestamp() + 5 }] })
+                .to_string(),
+        )
         .create();
 
-    let params = ImageQueryParams::with_key_type(key_type)
-        .resolution(resolution)
-        .sub_key(sub_key);
+    let map = client
+        .updated_map(&UpdatedParams::with_to_time(from, to))
+        .await?;
 
-    let _ = client.series_images_query(SERIES_ID, &params).await;
+    first_mock.assert();
+    second_mock.assert();
 
-    images_query_mock.assert();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&SeriesID(5)], from + Duration::seconds(30));
+    assert_eq!(map[&SeriesID(2)], second_chunk + Duration::seconds(5));
+
+    Ok(())
 }
 
 #[tokio::test]
-async fn client_series_images_query_params() {
+async fn client_updated_range() -> Result<()> {
     let client = authenticated_test_client().await;
 
-    let url = format!("/series/{}/images/query/params", SERIES_ID);
-
-    let images_query_params_mock = auth_mock(&client, GET, url.as_str()).create();
-
-    let _ = client.series_images_query_params(SERIES_ID).await;
+    let from = now_round_seconds() - Duration::days(20);
+    let second_window = from + Duration::days(UPDATED_MAX_DAYS);
+    let third_window = second_window + Duration::days(UPDATED_MAX_DAYS);
+    let to = from + Duration::days(20);
 
-    images_query_params_mock.assert();
-}
+    let window_mock = |window_from: DateTime<Utc>, window_to: DateTime<Utc>| {
+        auth_lang_mock(&client, GET, "/updated/query").match_query(AllOf(vec![
+            UrlEncoded("fromTime".to_string(), window_from.timestamp().to_string()),
+            UrlEncoded("toTime".to_string(), window_to.timestamp().to_string()),
+        ]))
+    };
 
-#[tokio::test]
-async fn client_episode() {
-    let client = authenticated_test_client().await;
+    let first_mock = window_mock(from, second_window)
+        .with_body(
+            json!({
+                "data": [
+                    { "id": 5, "lastUpdated": from.timestamp() + 30 },
+                    { "id": 2, "lastUpdated": from.timestamp() + 20 },
+                ]
+            })
+            .to_string(),
+        )
+        .create();
 
-    let url = format!("/episodes/{}", EPISODE_ID);
+    let empty_mock = window_mock(second_window, third_window)
+        .with_status(404)
+        .with_body(json!({ "Error": "No results for your query: map[]" }).to_string())
+        .create();
 
-    let episode_mock = auth_lang_mock(&client, GET, url.as_str()).create();
+    let third_mock = window_mock(third_window, to)
+        .with_body(
+            json!({ "data": [{ "id": 5, "lastUpdated": third_window.timestamp() + 5 }] })
+                .to_string(),
+        )
+        .create();
 
-    let _ = client.episode(EPISODE_ID).await;
+    let updates = client.updated_range(from, to).await?;
+
+    first_mock.assert();
+    empty_mock.assert();
+    third_mock.assert();
+
+    assert_eq!(
+        updates
+            .iter()
+            .map(|u| (u.id, u.last_updated))
+            .collect::<Vec<_>>(),
+        vec![
+            (SeriesID(2), from + Duration::seconds(20)),
+            (SeriesID(5), third_window + Duration::seconds(5)),
+        ]
+    );
 
-    episode_mock.assert();
+    Ok(())
 }
 
 #[tokio::test]
-async fn client_languages() {
+async fn client_user_favorites() -> Result<()> {
     let client = authenticated_test_client().await;
 
-    let languages_mock = auth_mock(&client, GET, "/languages").create();
+    let favorites_mock = auth_mock(&client, GET, "/user/favorites")
+        .with_body(json!({ "data": { "favorites": ["318408", "121361"] } }).to_string())
+        .create();
 
-    let _ = client.languages().await;
+    let favorites = client.user_favorites().await?;
 
-    languages_mock.assert();
-}
+    favorites_mock.assert();
 
-#[tokio::test]
-async fn client_language() {
-    let client = authenticated_test_client().await;
+    assert_eq!(favorites, vec![SeriesID(318408), SeriesID(121361)]);
 
-    let url = format!("/languages/{}", LANGUAGE_ID);
+    drop(favorites_mock);
 
-    let language_mock = auth_mock(&client, GET, url.as_str()).create();
+    let _empty_mock = auth_mock(&client, GET, "/user/favorites")
+        .with_body(json!({ "data": { "favorites": [""] } }).to_string())
+        .create();
 
-    let _ = client.language(LANGUAGE_ID).await;
+    assert_eq!(client.user_favorites().await?, vec![]);
 
-    language_mock.assert();
+    Ok(())
 }
 
 #[tokio::test]
-async fn client_updated() {
+async fn client_user_ratings() -> Result<()> {
     let client = authenticated_test_client().await;
 
-    let (from, to) = (
-        Utc::now() - Duration::days(9),
-        Utc::now() - Duration::days(4),
-    );
-
-    let updated_mock = auth_lang_mock(&client, GET, "/updated/query")
-        .match_query(AllOf(vec![
-            UrlEncoded("fromTime".to_string(), from.timestamp().to_string()),
-            UrlEncoded("toTime".to_string(), to.timestamp().to_string()),
-        ]))
+    let ratings_mock = auth_mock(&client, GET, "/user/ratings")
+        .with_body(
+            json!({
+                "data": [
+                    { "ratingType": "series", "ratingItemId": SERIES_ID, "rating": 9 },
+                    { "ratingType": "episode", "ratingItemId": EPISODE_ID, "rating": 4 },
+                ]
+            })
+            .to_string(),
+        )
         .create();
 
-    let params = UpdatedParams::with_to_time(from, to);
+    let ratings = client.user_ratings().await?;
 
-    let _ = client.updated(&params).await;
+    ratings_mock.assert();
 
-    updated_mock.assert();
+    assert_eq!(ratings.len(), 2);
+    assert_eq!(ratings[0].rating_type, RatingItemType::Series);
+    assert_eq!(ratings[0].rating_item_id, SERIES_ID);
+    assert_eq!(ratings[0].rating, 9);
+    assert_eq!(ratings[1].rating_type, RatingItemType::Episode);
+
+    Ok(())
 }
 
 #[tokio::test]
-async fn client_movie() {
+async fn client_add_remove_rating() -> Result<()> {
     let client = authenticated_test_client().await;
 
-    let url = format!("/movies/{}", MOVIE_ID);
+    let add_mock = auth_mock(
+        &client,
+        "PUT",
+        format!("/user/ratings/series/{}/7", SERIES_ID).as_str(),
+    )
+    .with_body(json!({ "data": [] }).to_string())
+    .create();
 
-    let movie_mock = auth_lang_mock(&client, GET, url.as_str()).create();
+    client
+        .add_rating(RatingItemType::Series, SERIES_ID, 7)
+        .await?;
 
-    let _ = client.movie(MOVIE_ID).await;
+    add_mock.assert();
 
-    movie_mock.assert();
-}
+    let remove_mock = auth_mock(&client, "DELETE", "/user/ratings/image/12345")
+        .with_body(json!({ "data": [] }).to_string())
+        .create();
 
-#[tokio::test]
-async fn client_movie_updates() {
-    let client = authenticated_test_client().await;
+    client.remove_rating(RatingItemType::Image, 12345).await?;
 
-    let since = Utc::now();
+    remove_mock.assert();
 
-    let movie_updates_mock = auth_mock(&client, GET, "/movieupdates")
-        .match_query(UrlEncoded(
-            "since".to_string(),
-            since.timestamp().to_string(),
-        ))
-        .create();
+    let unsent_mock = mock("PUT", Matcher::Any).expect(0).create();
 
-    let _ = client.movie_updates(since).await;
+    for rating in &[0, 11] {
+        match client
+            .add_rating(RatingItemType::Episode, EPISODE_ID, *rating)
+            .await
+            .unwrap_err()
+        {
+            Error::InvalidRating(r) => assert_eq!(r, *rating),
+            e => panic!("Wrong error kind: expected InvalidRating, got {:?}", e),
+        }
+    }
 
-    movie_updates_mock.assert();
+    unsent_mock.assert();
+
+    Ok(())
 }
 
 #[test]
@@ -560,7 +4070,42 @@ fn auth_lang_mock<P>(client: &Client, method: &str, path: P) -> Mock
 where
     P: Into<Matcher>,
 {
-    auth_mock(&client, method, path).match_header("accept-language", client.lang_abbr.as_str())
+    auth_mock(client, method, path).match_header("accept-language", client.lang_abbr.as_str())
+}
+
+fn series_mock(client: &Client, id: u32, rating: Option<&str>) -> Mock {
+    let series = Series {
+        id: SeriesID(id),
+        rating: rating.map(str::to_string),
+        ..Default::default()
+    };
+
+    auth_lang_mock(client, GET, format!("/series/{}", id).as_str())
+        .with_body(serde_json::to_string(&json!({ "data": series })).unwrap())
+        .create()
+}
+
+fn episode_page_body(
+    ids: &[u32],
+    first: u16,
+    last: u16,
+    next: Option<u16>,
+    prev: Option<u16>,
+) -> String {
+    let episodes: Vec<Episode> = ids
+        .iter()
+        .map(|id| Episode {
+            id: EpisodeID(*id),
+            series_id: SeriesID(SERIES_ID),
+            ..Default::default()
+        })
+        .collect();
+
+    serde_json::to_string(&json!({
+        "data": episodes,
+        "links": { "first": first, "last": last, "next": next, "prev": prev },
+    }))
+    .unwrap()
 }
 
 fn series_url() -> String {
@@ -3,10 +3,14 @@
 use std::error::Error as StdError;
 use std::fmt;
 use std::io::Error as IOError;
+use std::num::ParseIntError;
+use std::time::Duration;
 
 use chrono::format::ParseError as TimeParseError;
 use jsonwebtoken::errors::Error as JWTError;
+use reqwest::header::{InvalidHeaderName, InvalidHeaderValue};
 use reqwest::Error as ReqwestError;
+use serde_json::Error as JSONError;
 use url::ParseError as URLParseError;
 
 /// `Result` with error case set to `thetvdb::error::Error`.
@@ -22,17 +26,38 @@ pub enum Error {
     /// [`reqwest`]: https://docs.rs/reqwest/latest/reqwest/index.html
     Http(ReqwestError),
 
+    /// Occurs when the body of an API response cannot be decoded, either
+    /// because its compressed content is corrupt or because it does not match
+    /// the expected data.
+    ///
+    /// The underlying [`reqwest`] error is available as the error source.
+    ///
+    /// [`reqwest`]: https://docs.rs/reqwest/latest/reqwest/index.html
+    Decode(ReqwestError),
+
     /// IO error from `std`.
     IO(IOError),
 
     /// Occurs when the provided API key is not valid.
     InvalidAPIKey,
 
+    /// Occurs when a client is built with an empty api key.
+    MissingAPIKey,
+
     /// Occurs when TheTVDB API returns a `5XX` error response.
     ServerError,
 
     /// Occurs when resources (series, episodes, etc...) are not found.
-    NotFound,
+    ///
+    /// Contains the error message returned by the API, if any.
+    NotFound(Option<String>),
+
+    /// Occurs when TheTVDB API rate limits the client with a `429` response.
+    RateLimited {
+        /// How long to wait before sending another request, from the
+        /// response's `Retry-After` header, if present and valid.
+        retry_after: Option<Duration>,
+    },
 
     /// Occurs when a header returned by the API is not representable as a
     /// string.
@@ -43,7 +68,7 @@ pub enum Error {
     InvalidHTTPHeader(reqwest::header::ToStrError),
 
     /// Occurs when the API doesn't return a header containing the date and time
-    /// when the series was last modified.
+    /// when the series or episode was last modified.
     MissingLastModified,
 
     /// Occurs when the API returns dates and times in formats that are not
@@ -56,6 +81,24 @@ pub enum Error {
     /// [`Client::series_filter`]: ../client/struct.Client.html#method.series_filter
     MissingSeriesFilterKeys,
 
+    /// Occurs when [`SeriesFilterKeys::validate`] finds a key that the API
+    /// does not return when filtering series.
+    ///
+    /// [`SeriesFilterKeys::validate`]: ../params/struct.SeriesFilterKeys.html#method.validate
+    UnsupportedFilterKey(String),
+
+    /// Occurs when [`Client::add_rating`] is called with a rating that is not
+    /// between `1` and `10`.
+    ///
+    /// [`Client::add_rating`]: ../client/struct.Client.html#method.add_rating
+    InvalidRating(u8),
+
+    /// Occurs when a client is built with a
+    /// [`max_concurrent_requests`] limit of `0`.
+    ///
+    /// [`max_concurrent_requests`]: ../client/struct.ClientBuilder.html#method.max_concurrent_requests
+    InvalidConcurrencyLimit,
+
     /// Occurs when a image URL method is called, but the image file path is not
     /// known.
     MissingImage,
@@ -67,8 +110,62 @@ pub enum Error {
     /// Occurs when a URL cannot be parsed.
     InvalidUrl(URLParseError),
 
-    /// Occurs when the JWT returned by the API on login is invalid.
+    /// Occurs when the JWT returned by the API on login has a valid header,
+    /// but its claims cannot be decoded.
+    ///
+    /// Tokens that are not JWTs at all are accepted as opaque tokens with a
+    /// conservative expiry instead.
     InvalidJWT(JWTError),
+
+    /// Occurs when the JWT returned by the API on login can be decoded, but
+    /// its claims are not usable (e.g. `exp` is not after `orig_iat`).
+    InvalidTokenPayload,
+
+    /// Occurs when a header name passed to [`ClientBuilder::default_header`]
+    /// is not valid.
+    ///
+    /// [`ClientBuilder::default_header`]: ../client/struct.ClientBuilder.html#method.default_header
+    InvalidHeaderName(InvalidHeaderName),
+
+    /// Occurs when a header value passed to [`ClientBuilder::default_header`]
+    /// is not valid.
+    ///
+    /// [`ClientBuilder::default_header`]: ../client/struct.ClientBuilder.html#method.default_header
+    InvalidHeaderValue(InvalidHeaderValue),
+
+    /// Occurs when JSON data does not match the expected type, e.g. when
+    /// passed to [`Series::from_value`].
+    ///
+    /// [`Series::from_value`]: ../response/struct.Series.html#method.from_value
+    InvalidJSON(JSONError),
+
+    /// Occurs when a string id, e.g. passed to [`EpisodeParams::try_from_str`],
+    /// is not a valid number.
+    ///
+    /// [`EpisodeParams::try_from_str`]: ../params/struct.EpisodeParams.html#method.try_from_str
+    InvalidID(ParseIntError),
+
+    /// Occurs when the id of the data returned by the API is not the
+    /// requested one.
+    ///
+    /// Only checked if enabled with [`ClientBuilder::verify_ids`].
+    ///
+    /// [`ClientBuilder::verify_ids`]: ../client/struct.ClientBuilder.html#method.verify_ids
+    IdMismatch {
+        /// The requested id.
+        requested: u64,
+        /// The id returned by the API.
+        returned: u64,
+    },
+
+    /// Combines the errors of many failed requests, for callers of bulk
+    /// methods like [`Client::series_many_partial`] who want to report them
+    /// as a single error.
+    ///
+    /// Not returned by any method of this crate.
+    ///
+    /// [`Client::series_many_partial`]: ../client/struct.Client.html#method.series_many_partial
+    Multiple(Vec<Error>),
 }
 
 impl fmt::Display for Error {
@@ -77,18 +174,56 @@ impl fmt::Display for Error {
 
         match self {
             Http(e) => write!(f, "HTTP error: {}", e),
+            Decode(e) => write!(f, "Could not decode response body: {}", e),
             IO(e) => write!(f, "IO error: {}", e),
             InvalidAPIKey => write!(f, "Invalid API key"),
+            MissingAPIKey => write!(f, "No API key provided"),
             ServerError => write!(f, "API Server error"),
-            NotFound => write!(f, "Not found"),
+            NotFound(Some(message)) => write!(f, "Not found: {}", message),
+            NotFound(None) => write!(f, "Not found"),
+            RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(
+                f,
+                "Rate limited, retry after {} seconds",
+                retry_after.as_secs()
+            ),
+            RateLimited { retry_after: None } => write!(f, "Rate limited"),
             InvalidHTTPHeader(e) => write!(f, "Non-parsable HTTP header: {}", e),
             MissingLastModified => write!(f, "Last modified data missing"),
             InvalidDateFormat(e) => write!(f, "Invalid date format: {}", e),
             MissingSeriesFilterKeys => write!(f, "No series filter keys provided"),
+            UnsupportedFilterKey(key) => write!(f, "Unsupported series filter key: {}", key),
+            InvalidRating(rating) => write!(f, "Invalid rating {}, expected 1 to 10", rating),
+            InvalidConcurrencyLimit => write!(f, "Concurrent request limit must be at least 1"),
             MissingImage => write!(f, "Image data is missing"),
             MissingSeriesSlug => write!(f, "Series slug is missing"),
             InvalidUrl(e) => write!(f, "Invalid URL: {}", e),
             InvalidJWT(e) => write!(f, "Could not decode authentication JWT: {}", e),
+            InvalidTokenPayload => write!(f, "Invalid authentication JWT payload"),
+            InvalidHeaderName(e) => write!(f, "Invalid header name: {}", e),
+            InvalidHeaderValue(e) => write!(f, "Invalid header value: {}", e),
+            InvalidJSON(e) => write!(f, "Invalid JSON data: {}", e),
+            InvalidID(e) => write!(f, "Invalid id: {}", e),
+            IdMismatch {
+                requested,
+                returned,
+            } => write!(
+                f,
+                "Requested id {}, but the API returned id {}",
+                requested, returned
+            ),
+            Multiple(errors) => {
+                write!(f, "{} errors occurred", errors.len())?;
+
+                for (i, e) in errors.iter().enumerate() {
+                    let sep = if i == 0 { ": " } else { "; " };
+
+                    write!(f, "{}{}", sep, e)?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
@@ -99,25 +234,42 @@ impl StdError for Error {
 
         match self {
             Http(e) => Some(e),
+            Decode(e) => Some(e),
             IO(e) => Some(e),
             InvalidHTTPHeader(e) => Some(e),
             InvalidDateFormat(e) => Some(e),
             InvalidUrl(e) => Some(e),
             InvalidJWT(e) => Some(e),
+            InvalidHeaderName(e) => Some(e),
+            InvalidHeaderValue(e) => Some(e),
+            InvalidJSON(e) => Some(e),
+            InvalidID(e) => Some(e),
             InvalidAPIKey
+            | MissingAPIKey
             | ServerError
-            | NotFound
+            | NotFound(_)
+            | RateLimited { .. }
             | MissingLastModified
             | MissingSeriesFilterKeys
+            | UnsupportedFilterKey(_)
+            | InvalidRating(_)
+            | InvalidConcurrencyLimit
             | MissingImage
-            | MissingSeriesSlug => None,
+            | MissingSeriesSlug
+            | InvalidTokenPayload
+            | IdMismatch { .. }
+            | Multiple(_) => None,
         }
     }
 }
 
 impl From<ReqwestError> for Error {
     fn from(e: ReqwestError) -> Self {
-        Self::Http(e)
+        if e.is_decode() {
+            Self::Decode(e)
+        } else {
+            Self::Http(e)
+        }
     }
 }
 
@@ -151,6 +303,30 @@ impl From<JWTError> for Error {
     }
 }
 
+impl From<InvalidHeaderName> for Error {
+    fn from(e: InvalidHeaderName) -> Self {
+        Self::InvalidHeaderName(e)
+    }
+}
+
+impl From<InvalidHeaderValue> for Error {
+    fn from(e: InvalidHeaderValue) -> Self {
+        Self::InvalidHeaderValue(e)
+    }
+}
+
+impl From<JSONError> for Error {
+    fn from(e: JSONError) -> Self {
+        Self::InvalidJSON(e)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Self::InvalidID(e)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,4 +337,15 @@ mod tests {
 
         assert_send_sync::<Error>();
     }
+
+    #[test]
+    fn multiple_display() {
+        let error = Error::Multiple(vec![Error::NotFound(None), Error::ServerError]);
+
+        assert_eq!(
+            error.to_string(),
+            "2 errors occurred: Not found; API Server error"
+        );
+        assert_eq!(Error::Multiple(vec![]).to_string(), "0 errors occurred");
+    }
 }
@@ -0,0 +1,201 @@
+use chrono::Datelike;
+
+use crate::response::SearchSeries;
+
+// weight of the name similarity when the years can be compared
+const NAME_WEIGHT: f32 = 0.8;
+
+/// Score how likely it is that a search result is the series with the given
+/// name and first aired year.
+///
+/// Returns a value between `0.0` (no match) and `1.0` (best match).
+///
+/// Names are compared ignoring case, punctuation and a trailing parenthesized
+/// part (e.g. `"Doctor Who (2005)"` matches `"doctor who"`), against the
+/// series name and all its aliases. If both `year` and the series'
+/// `first_aired` date are known, the score also rewards matching years,
+/// with partial credit for years that differ by one. Otherwise only names
+/// are compared.
+///
+/// Also available as [`SearchSeries::match_score`].
+///
+/// # Examples
+/// ```no_run
+/// # use thetvdb::{Client, error::Result, params::SearchBy};
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> Result<()> {
+/// #
+/// # let client = Client::new("KEY").await?;
+/// #
+/// let results = client.search(SearchBy::Name("Doctor Who")).await?;
+///
+/// let best = results.iter().max_by(|a, b| {
+///     let a = thetvdb::fuzzy_match(a, "Doctor Who", Some(2005));
+///     let b = thetvdb::fuzzy_match(b, "Doctor Who", Some(2005));
+///
+///     a.partial_cmp(&b).unwrap()
+/// });
+/// # Ok(()) }
+/// ```
+///
+/// [`SearchSeries::match_score`]: response/struct.SearchSeries.html#method.match_score
+pub fn fuzzy_match(series: &SearchSeries, name: &str, year: Option<i32>) -> f32 {
+    let name = normalize(name);
+
+    let name_score = series
+        .series_name
+        .iter()
+        .chain(series.aliases.iter())
+        .map(|n| similarity(&normalize(n), &name))
+        .fold(0.0, f32::max);
+
+    let first_aired_year = series.first_aired.map(|d| d.year());
+
+    match (year, first_aired_year) {
+        (Some(year), Some(first_aired_year)) => {
+            let year_score = match (year - first_aired_year).abs() {
+                0 => 1.0,
+                1 => 0.5,
+                _ => 0.0,
+            };
+
+            name_score * NAME_WEIGHT + year_score * (1.0 - NAME_WEIGHT)
+        }
+        _ => name_score,
+    }
+}
+
+// lowercase words of alphanumeric characters, without a trailing
+// parenthesized part
+fn normalize(name: &str) -> Vec<char> {
+    let name = name.trim();
+
+    let name = match name.rfind('(') {
+        Some(i) if i > 0 && name.ends_with(')') => &name[..i],
+        _ => name,
+    };
+
+    let mut normalized = Vec::new();
+
+    for word in name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+
+        normalized.extend(word.chars().flat_map(char::to_lowercase));
+    }
+
+    normalized
+}
+
+// 1 - normalized Levenshtein distance
+fn similarity(a: &[char], b: &[char]) -> f32 {
+    let max_len = a.len().max(b.len());
+
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    1.0 - levenshtein(a, b) as f32 / max_len as f32
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut costs: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.iter().enumerate() {
+        let mut prev = costs[0];
+        costs[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let current = costs[j + 1];
+
+            costs[j + 1] = if ca == cb {
+                prev
+            } else {
+                1 + prev.min(current).min(costs[j])
+            };
+
+            prev = current;
+        }
+    }
+
+    costs[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn search_series(name: &str, aliases: &[&str], year: Option<i32>) -> SearchSeries {
+        SearchSeries {
+            series_name: Some(name.to_string()),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            first_aired: year.map(|y| NaiveDate::from_ymd_opt(y, 3, 26).unwrap()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn normalize_names() {
+        let normalized = |n| normalize(n).into_iter().collect::<String>();
+
+        assert_eq!(normalized("Doctor Who (2005)"), "doctor who");
+        assert_eq!(
+            normalized("  Marvel's  Agents of S.H.I.E.L.D. "),
+            "marvel s agents of s h i e l d"
+        );
+        assert_eq!(normalized("(500) Days"), "500 days");
+        assert_eq!(normalized(""), "");
+    }
+
+    #[test]
+    fn levenshtein_distance() {
+        let distance = |a: &str, b: &str| {
+            levenshtein(
+                &a.chars().collect::<Vec<_>>(),
+                &b.chars().collect::<Vec<_>>(),
+            )
+        };
+
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("", "abc"), 3);
+        assert_eq!(distance("same", "same"), 0);
+    }
+
+    #[test]
+    fn fuzzy_match_scores() {
+        let series = search_series("Doctor Who (2005)", &["Dr Who"], Some(2005));
+
+        assert!((fuzzy_match(&series, "Doctor Who", Some(2005)) - 1.0).abs() < f32::EPSILON);
+        assert!((fuzzy_match(&series, "dr. who", None) - 1.0).abs() < f32::EPSILON);
+        assert!((fuzzy_match(&series, "Doctor Who", Some(2006)) - 0.9).abs() < 1e-6);
+        assert!((fuzzy_match(&series, "Doctor Who", Some(1963)) - 0.8).abs() < 1e-6);
+
+        let classic = search_series("Doctor Who", &[], Some(1963));
+
+        assert!(
+            fuzzy_match(&series, "Doctor Who", Some(2005))
+                > fuzzy_match(&classic, "Doctor Who", Some(2005))
+        );
+
+        let other = search_series("Planet Earth II", &[], Some(2016));
+
+        assert!(fuzzy_match(&other, "Doctor Who", None) < 0.5);
+        assert!(
+            (series.match_score("Doctor Who", Some(2005))
+                - fuzzy_match(&series, "Doctor Who", Some(2005)))
+            .abs()
+                < f32::EPSILON
+        );
+
+        let unnamed = SearchSeries::default();
+
+        assert!(fuzzy_match(&unnamed, "Doctor Who", None).abs() < f32::EPSILON);
+    }
+}
@@ -27,7 +27,7 @@ impl From<u16> for LanguageID {
 /// Can be used to [set the client language][1].
 ///
 /// [1]: ../client/struct.Client.html#method.set_language
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
 #[non_exhaustive]
 #[serde(rename_all = "camelCase")]
 pub struct Language {
@@ -41,8 +41,68 @@ pub struct Language {
     pub english_name: String,
 }
 
+impl Language {
+    /// Returns a language tag for this language, as expected by locale
+    /// libraries and other systems using [BCP 47] tags.
+    ///
+    /// TheTVDB abbreviations are mostly [ISO 639-1] codes, which are valid
+    /// tags already, so they are returned unchanged, except for:
+    ///
+    /// | Abbreviation | Tag       | Reason                                  |
+    /// |--------------|-----------|-----------------------------------------|
+    /// | `zh`         | `zh-Hans` | TheTVDB Chinese uses simplified script  |
+    /// | `no`         | `nb`      | TheTVDB Norwegian is Bokmål             |
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use thetvdb::{Client, error::Result};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// #
+    /// # let client = Client::new("KEY").await?;
+    /// #
+    /// let chinese = client.language_by_abbr("zh").await?.unwrap();
+    ///
+    /// assert_eq!(chinese.as_language_tag(), "zh-Hans");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [BCP 47]: https://tools.ietf.org/html/bcp47
+    /// [ISO 639-1]: https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes
+    pub fn as_language_tag(&self) -> String {
+        match self.abbreviation.as_str() {
+            "zh" => "zh-Hans".to_string(),
+            "no" => "nb".to_string(),
+            abbr => abbr.to_string(),
+        }
+    }
+}
+
 impl From<&Language> for LanguageID {
     fn from(language: &Language) -> LanguageID {
         language.id
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn language(abbreviation: &str) -> Language {
+        Language {
+            id: LanguageID(1),
+            abbreviation: abbreviation.to_string(),
+            name: String::new(),
+            english_name: String::new(),
+        }
+    }
+
+    #[test]
+    fn language_as_language_tag() {
+        assert_eq!(language("zh").as_language_tag(), "zh-Hans");
+        assert_eq!(language("no").as_language_tag(), "nb");
+        assert_eq!(language("pt").as_language_tag(), "pt");
+        assert_eq!(language("en").as_language_tag(), "en");
+    }
+}
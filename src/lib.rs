@@ -66,6 +66,7 @@
 //!
 //! [client]: ./client/struct.Client.html
 //! [API Keys page]: https://thetvdb.com/dashboard/account/apikeys
+mod fuzzy;
 mod serialization;
 mod urls;
 
@@ -76,9 +77,10 @@ pub mod params;
 pub mod response;
 
 #[doc(inline)]
-pub use client::Client;
+pub use client::{Client, ClientBuilder};
 #[doc(inline)]
 pub use error::{Error, Result};
+pub use fuzzy::fuzzy_match;
 
 #[cfg(test)]
 mod test_util {
@@ -87,7 +89,7 @@ mod test_util {
     use crate::error::Error;
 
     pub fn now_round_seconds() -> DateTime<Utc> {
-        Utc.timestamp(Utc::now().timestamp(), 0)
+        Utc.timestamp_opt(Utc::now().timestamp(), 0).unwrap()
     }
 
     pub fn wrong_error_kind(expected: Error, got: Error) {
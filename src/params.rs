@@ -1,8 +1,9 @@
 //! Parameters used by `Client` to send API requests.
 
-use chrono::{DateTime, Utc};
-use serde::Serialize;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
 
+use crate::error::{Error, Result};
 use crate::response::SeriesID;
 use crate::serialization as ser;
 
@@ -37,6 +38,42 @@ where
     }
 }
 
+impl SearchBy<String> {
+    /// Create a [`Slug`](#variant.Slug) search parameter from a series
+    /// title.
+    ///
+    /// The title is transformed the way TheTVDB creates its slugs: it is
+    /// lowercased, each run of whitespace or hyphens is replaced with a single
+    /// hyphen and all other characters that are not alphanumeric are removed.
+    ///
+    /// # Examples
+    /// ```
+    /// use thetvdb::params::SearchBy;
+    ///
+    /// let slug = SearchBy::slug_from_title("Marvel's Agents of S.H.I.E.L.D.");
+    ///
+    /// match slug {
+    ///     SearchBy::Slug(slug) => assert_eq!(slug, "marvels-agents-of-shield"),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn slug_from_title(title: &str) -> Self {
+        let slug = title
+            .split(|c: char| c.is_whitespace() || c == '-')
+            .map(|word| {
+                word.chars()
+                    .filter(|c| c.is_alphanumeric())
+                    .flat_map(char::to_lowercase)
+                    .collect::<String>()
+            })
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+
+        SearchBy::Slug(slug)
+    }
+}
+
 /// Parameters used to get a series' episodes with
 /// [`Client::series_episodes`](../client/struct.Client.html#method.series_episodes).
 #[derive(Debug, PartialEq, Eq)]
@@ -66,6 +103,16 @@ impl EpisodeParams {
         Self { series_id, page }
     }
 
+    /// Create new parameters for the series with the given string id.
+    ///
+    /// # Errors
+    /// Will fail with [`Error::InvalidID`] if the id is not a valid number.
+    ///
+    /// [`Error::InvalidID`]: ../error/enum.Error.html#variant.InvalidID
+    pub fn try_from_str(series_id: &str) -> Result<Self> {
+        Ok(Self::new(series_id.parse::<SeriesID>()?))
+    }
+
     /// Set the `page` parameter.
     pub fn page(mut self, page: u16) -> Self {
         self.page = page;
@@ -135,6 +182,11 @@ pub(crate) struct EpisodeQuery {
     pub(crate) dvd_episode: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) imdb_id: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "ser::optional_naive_date"
+    )]
+    pub(crate) first_aired: Option<NaiveDate>,
 }
 
 /// Parameters used to query for a series episodes with
@@ -168,6 +220,16 @@ impl EpisodeQueryParams {
         }
     }
 
+    /// Create new parameters for the series with the given string id.
+    ///
+    /// # Errors
+    /// Will fail with [`Error::InvalidID`] if the id is not a valid number.
+    ///
+    /// [`Error::InvalidID`]: ../error/enum.Error.html#variant.InvalidID
+    pub fn try_from_str(series_id: &str) -> Result<Self> {
+        Ok(Self::new(series_id.parse::<SeriesID>()?))
+    }
+
     pub(crate) fn with_page_query<I>(series_id: I, page: u16, query: EpisodeQuery) -> Self
     where
         I: Into<SeriesID>,
@@ -222,6 +284,14 @@ impl EpisodeQueryParams {
         self.query.imdb_id = Some(id.into());
         self
     }
+
+    /// Set the `firstAired` parameter.
+    ///
+    /// Matches the episodes first aired on the given date.
+    pub fn first_aired(mut self, date: NaiveDate) -> Self {
+        self.query.first_aired = Some(date);
+        self
+    }
 }
 
 /// Trait used to create episode query parameters.
@@ -271,6 +341,9 @@ impl SeriesFilterKeys {
     /// If all keys are added, this many bytes would be used.
     const FULL_CAPACITY: usize = 221;
 
+    /// Keys accepted by the API, but never included in the filtered series.
+    const UNSUPPORTED_KEYS: &'static [&'static str] = &["lastUpdated"];
+
     /// Create a new list of filter keys.
     pub fn new() -> Self {
         Self {
@@ -284,6 +357,9 @@ impl SeriesFilterKeys {
     }
 
     /// Add `last_updated` to key list.
+    ///
+    /// The API does not return this field when filtering, see
+    /// [`validate`](#method.validate).
     pub fn last_updated(self) -> Self {
         self.push_key("lastUpdated")
     }
@@ -413,6 +489,29 @@ impl SeriesFilterKeys {
         self.keys_query.is_empty()
     }
 
+    /// Check that the API returns all the keys in the list.
+    ///
+    /// Some keys (like `last_updated`) are accepted by the API but silently
+    /// left out of the response. [`Client::series_filter`] does not call this
+    /// method, so such keys are simply `None` in the returned series.
+    ///
+    /// # Errors
+    /// Will return [`Error::UnsupportedFilterKey`] with the first key that the
+    /// API is known to not return.
+    ///
+    /// [`Client::series_filter`]: ../client/struct.Client.html#method.series_filter
+    /// [`Error::UnsupportedFilterKey`]: ../error/enum.Error.html#variant.UnsupportedFilterKey
+    pub fn validate(&self) -> Result<()> {
+        match self
+            .keys_query
+            .split(',')
+            .find(|k| Self::UNSUPPORTED_KEYS.contains(k))
+        {
+            Some(key) => Err(Error::UnsupportedFilterKey(key.to_string())),
+            None => Ok(()),
+        }
+    }
+
     #[cfg(test)]
     pub(crate) fn is_at_full_capacity(&self) -> bool {
         self.keys_query.len() == Self::FULL_CAPACITY
@@ -519,9 +618,9 @@ impl ImageQueryParams {
 #[serde(rename_all = "camelCase")]
 pub struct UpdatedParams {
     #[serde(serialize_with = "chrono::serde::ts_seconds::serialize")]
-    from_time: DateTime<Utc>,
+    pub(crate) from_time: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    to_time: Option<ser::Timestamp>,
+    pub(crate) to_time: Option<ser::Timestamp>,
 }
 
 impl UpdatedParams {
@@ -559,5 +658,28 @@ impl UpdatedParams {
     }
 }
 
+/// Type of the items that can be rated with
+/// [`Client::add_rating`](../client/struct.Client.html#method.add_rating).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RatingItemType {
+    /// A series.
+    Series,
+    /// An episode.
+    Episode,
+    /// A series image.
+    Image,
+}
+
+impl RatingItemType {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            RatingItemType::Series => "series",
+            RatingItemType::Episode => "episode",
+            RatingItemType::Image => "image",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
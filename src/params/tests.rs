@@ -19,6 +19,27 @@ fn search_by_to_query_param() {
     assert_eq!(SearchBy::Slug(slug).query_param(), [("slug", slug)]);
 }
 
+#[test]
+fn search_by_slug_from_title() {
+    let cases = vec![
+        ("Planet Earth II", "planet-earth-ii"),
+        ("  The   Office ", "the-office"),
+        (
+            "Marvel's Agents of S.H.I.E.L.D.",
+            "marvels-agents-of-shield",
+        ),
+        ("Star Trek - Deep Space Nine", "star-trek-deep-space-nine"),
+        ("already-a-slug", "already-a-slug"),
+        ("", ""),
+    ];
+
+    for (title, expected) in cases {
+        let search_by = SearchBy::slug_from_title(title);
+
+        assert_eq!(search_by.query_param(), [("slug", expected)]);
+    }
+}
+
 #[test]
 fn series_filter_keys() {
     let mut keys = SeriesFilterKeys::new();
@@ -144,3 +165,44 @@ fn types_send_sync() {
     assert_send_sync::<ImageQueryParams>();
     assert_send_sync::<UpdatedParams>();
 }
+
+#[test]
+fn episode_params_try_from_str() {
+    let params = EpisodeParams::try_from_str("318408").unwrap();
+
+    assert_eq!(params.series_id, SeriesID(318408));
+    assert_eq!(params.page, 1);
+
+    let query_params = EpisodeQueryParams::try_from_str("318408").unwrap();
+
+    assert_eq!(query_params.params.series_id, SeriesID(318408));
+
+    for invalid in &["", "abc", "-1", "318408x"] {
+        match EpisodeParams::try_from_str(invalid).unwrap_err() {
+            crate::error::Error::InvalidID(_) => {}
+            e => panic!("Wrong error kind: expected InvalidID, got {:?}", e),
+        }
+
+        assert!(EpisodeQueryParams::try_from_str(invalid).is_err());
+    }
+}
+
+#[test]
+fn series_filter_keys_validate() {
+    assert!(SeriesFilterKeys::new().validate().is_ok());
+    assert!(SeriesFilterKeys::new()
+        .series_name()
+        .network()
+        .validate()
+        .is_ok());
+
+    let keys = SeriesFilterKeys::new().series_name().last_updated().id();
+
+    match keys.validate().unwrap_err() {
+        Error::UnsupportedFilterKey(key) => assert_eq!(key, "lastUpdated"),
+        e => panic!(
+            "Wrong error kind: expected UnsupportedFilterKey, got {:?}",
+            e
+        ),
+    }
+}
@@ -1,14 +1,34 @@
 //! Types used to deserialize and work with data received from the API.
+//!
+//! # Dates and times
+//! The API returns dates and times in two forms:
+//! * Unix timestamps (e.g. [`Series::last_updated`]), which are always UTC.
+//! * Text like `"2017-06-28 10:48:00"` (e.g. [`Series::added`]), without
+//!   any time zone information.
+//!
+//! The API does not document which time zone the text dates and times are
+//! in. This crate assumes UTC, so the resulting `DateTime<Utc>` may be off
+//! by a few hours if they are actually in another zone. Fields of this form
+//! have `*_naive` accessors returning the date and time exactly as sent by
+//! the API, e.g. [`Series::added_naive`].
+//!
+//! [`Series::last_updated`]: struct.Series.html#structfield.last_updated
+//! [`Series::added`]: struct.Series.html#structfield.added
+//! [`Series::added_naive`]: struct.Series.html#method.added_naive
 
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::num::ParseIntError;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
 
-use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use optfield::optfield;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::error::*;
-use crate::params::{EpisodeParams, EpisodeQuery, EpisodeQueryParams};
+use crate::params::{EpisodeParams, EpisodeQuery, EpisodeQueryParams, RatingItemType};
 use crate::serialization as ser;
 use crate::urls;
 
@@ -41,6 +61,14 @@ impl From<u32> for SeriesID {
     }
 }
 
+impl FromStr for SeriesID {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
 impl From<&SearchSeries> for SeriesID {
     fn from(s: &SearchSeries) -> SeriesID {
         s.id
@@ -127,6 +155,38 @@ pub struct SearchSeries {
     pub status: SeriesStatus,
 }
 
+/// Search results returned by [`Client::search_page`].
+///
+/// The API does not paginate search results at the moment, so all results
+/// are returned in [`data`](#structfield.data) and there are no page links.
+///
+/// [`Client::search_page`]: ../client/struct.Client.html#method.search_page
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[non_exhaustive]
+pub struct SearchResponse<S = SearchSeries> {
+    /// The search results.
+    pub data: Vec<S>,
+    #[serde(default)]
+    pub(crate) links: Option<PageLinks>,
+}
+
+impl<S> SearchResponse<S> {
+    /// Returns `true` if the API returned page links with the results.
+    pub fn is_paginated(&self) -> bool {
+        self.links.is_some()
+    }
+
+    /// The next page, if available.
+    pub fn next_page(&self) -> Option<u16> {
+        self.links.as_ref().and_then(|l| l.next)
+    }
+
+    /// The previous page, if available.
+    pub fn prev_page(&self) -> Option<u16> {
+        self.links.as_ref().and_then(|l| l.prev)
+    }
+}
+
 macro_rules! series_banner_url_method {
     () => {
         /// Returns the full URL to the series' banner.
@@ -156,6 +216,16 @@ impl SearchSeries {
     series_banner_url_method!();
 
     series_website_url_method!();
+
+    /// Score how likely it is that this is the series with the given name
+    /// and first aired year, between `0.0` and `1.0`.
+    ///
+    /// See [`fuzzy_match`] for more info.
+    ///
+    /// [`fuzzy_match`]: ../fn.fuzzy_match.html
+    pub fn match_score(&self, name: &str, year: Option<i32>) -> f32 {
+        crate::fuzzy_match(self, name, year)
+    }
 }
 
 #[optfield(
@@ -173,15 +243,12 @@ For more info see [`Client::series_filter`].
 [`Series`]: struct.Series.html
     "#,
     attrs = (
-        derive(Clone, Debug, PartialEq, Deserialize, Serialize),
-        cfg_attr(test, derive(Default)),
+        derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize),
         non_exhaustive,
-        serde(rename_all = "camelCase")
+        serde(default, rename_all = "camelCase")
     ),
     field_doc,
-    field_attrs = add(
-        serde(default)
-    )
+    field_attrs
 )]
 /// Full series data returned by [`Client::series`].
 ///
@@ -199,6 +266,8 @@ pub struct Series {
     #[serde(deserialize_with = "ser::optional_string")]
     pub series_name: Option<String>,
     /// The date and time when the series was added to TheTVDB.
+    ///
+    /// Assumed to be UTC, see [Dates and times](index.html#dates-and-times).
     #[serde(with = "ser::optional_date_time")]
     pub added: Option<DateTime<Utc>>,
     // although not in the official docs,
@@ -260,6 +329,9 @@ pub struct Series {
     #[serde(deserialize_with = "ser::optional_float")]
     pub site_rating: Option<f32>,
     /// Number of rating votes.
+    ///
+    /// Missing or `null` counts are deserialized as `0`.
+    #[serde(default, deserialize_with = "ser::null_as_default")]
     pub site_rating_count: u32,
     /// Series website slug.
     ///
@@ -300,6 +372,99 @@ impl Series {
     series_url_methods!();
 
     series_website_url_method!();
+
+    /// Deserialize a series from JSON data, as returned by the API in the
+    /// `data` field of the `/series/{id}` response.
+    ///
+    /// Useful to restore series stored in their raw form, handling the API's
+    /// quirks (empty strings, date formats, etc...) the same way [`Client`]
+    /// does.
+    ///
+    /// # Errors
+    /// Will fail if the JSON data is not a valid series.
+    ///
+    /// [`Client`]: ../client/struct.Client.html
+    pub fn from_value(value: serde_json::Value) -> Result<Self> {
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Returns `added` as sent by the API, without a time zone.
+    ///
+    /// See [Dates and times](index.html#dates-and-times) for more info.
+    pub fn added_naive(&self) -> Option<NaiveDateTime> {
+        self.added.map(|dt| dt.naive_utc())
+    }
+
+    /// Returns the date when the series first aired as a `DateTime`, at
+    /// midnight UTC.
+    ///
+    /// Returns `None` if `first_aired` is `None`.
+    pub fn first_aired_datetime(&self) -> Option<DateTime<Utc>> {
+        self.first_aired.map(midnight_utc)
+    }
+
+    /// Returns the series' `rating` as a [`ContentRating`].
+    ///
+    /// Returns `None` if the rating is missing or not a known US TV rating.
+    ///
+    /// [`ContentRating`]: enum.ContentRating.html
+    pub fn content_rating(&self) -> Option<ContentRating> {
+        self.rating
+            .as_ref()
+            .and_then(|r| ContentRating::from_rating(r))
+    }
+
+    /// Returns the series' network, or `"Unknown"` if the network is missing
+    /// or empty.
+    ///
+    /// The API only returns a single network per series, even for series
+    /// that moved between networks.
+    pub fn network_or_unknown(&self) -> &str {
+        match self.network.as_ref() {
+            Some(n) if !n.trim().is_empty() => n,
+            _ => "Unknown",
+        }
+    }
+
+    /// Returns a hash of the series data.
+    ///
+    /// Can be used to detect changes by comparing it to the hash of a
+    /// previously stored copy of the same series.
+    ///
+    /// Fields are hashed in declaration order, so the result is the same across
+    /// runs and platforms. `last_updated` is not included, so only changes of
+    /// the actual data are taken into account.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = StableHasher::new();
+
+        self.id.hash(&mut hasher);
+        self.series_name.hash(&mut hasher);
+        self.added.hash(&mut hasher);
+        self.added_by.hash(&mut hasher);
+        self.airs_day_of_week.hash(&mut hasher);
+        self.airs_time.hash(&mut hasher);
+        self.aliases.hash(&mut hasher);
+        self.season.hash(&mut hasher);
+        self.banner.hash(&mut hasher);
+        self.poster.hash(&mut hasher);
+        self.fanart.hash(&mut hasher);
+        self.first_aired.hash(&mut hasher);
+        self.genre.hash(&mut hasher);
+        self.imdb_id.hash(&mut hasher);
+        self.network.hash(&mut hasher);
+        self.network_id.hash(&mut hasher);
+        self.overview.hash(&mut hasher);
+        self.rating.hash(&mut hasher);
+        self.runtime.hash(&mut hasher);
+        self.language.hash(&mut hasher);
+        self.site_rating.map(f32::to_bits).hash(&mut hasher);
+        self.site_rating_count.hash(&mut hasher);
+        self.slug.hash(&mut hasher);
+        self.status.hash(&mut hasher);
+        self.zap2it_id.hash(&mut hasher);
+
+        hasher.finish()
+    }
 }
 
 impl FilteredSeries {
@@ -312,14 +477,14 @@ impl FilteredSeries {
     /// and cannot be parsed into an `Url`.
     pub fn website_url(&self) -> Result<Url> {
         match self.slug.as_ref() {
-            Some(s) => urls::series_website(&s),
+            Some(s) => urls::series_website(s),
             None => Err(Error::MissingSeriesSlug),
         }
     }
 }
 
 /// Possible series status.
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub enum SeriesStatus {
     /// Series has ended and no more episodes will be aired.
@@ -333,13 +498,57 @@ pub enum SeriesStatus {
     Unknown,
 }
 
+// `#[default]` on enum variants requires a newer rustc than the minimum
+// supported version
 #[cfg(test)]
+#[allow(clippy::derivable_impls)]
 impl Default for SeriesStatus {
     fn default() -> Self {
         Self::Unknown
     }
 }
 
+/// US TV parental guidelines rating of a series.
+///
+/// Variants are ordered from the least to the most restrictive, so ratings can
+/// be compared (e.g. `ContentRating::TVPG < ContentRating::TV14`).
+///
+/// See [`Series::content_rating`].
+///
+/// [`Series::content_rating`]: struct.Series.html#method.content_rating
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ContentRating {
+    /// `TV-Y`: suitable for all children.
+    TVY,
+    /// `TV-Y7`: suitable for children 7 and older.
+    TVY7,
+    /// `TV-G`: suitable for all ages.
+    TVG,
+    /// `TV-PG`: parental guidance suggested.
+    TVPG,
+    /// `TV-14`: unsuitable for children under 14.
+    TV14,
+    /// `TV-MA`: for mature audiences only.
+    TVMA,
+}
+
+impl ContentRating {
+    fn from_rating(rating: &str) -> Option<Self> {
+        use ContentRating::*;
+
+        match rating.trim() {
+            "TV-Y" => Some(TVY),
+            "TV-Y7" | "TV-Y7-FV" => Some(TVY7),
+            "TV-G" => Some(TVG),
+            "TV-PG" => Some(TVPG),
+            "TV-14" => Some(TV14),
+            "TV-MA" => Some(TVMA),
+            _ => None,
+        }
+    }
+}
+
 /// Actor data returned by [`Client::series_actors`].
 ///
 /// See linked method for more info.
@@ -367,10 +576,14 @@ pub struct Actor {
     pub image: Option<String>,
     /// Image author.
     pub image_author: Option<u32>,
-    #[serde(with = "ser::optional_date_time")]
     /// Date and time when the image was added.
+    ///
+    /// Assumed to be UTC, see [Dates and times](index.html#dates-and-times).
+    #[serde(with = "ser::optional_date_time")]
     pub image_added: Option<DateTime<Utc>>,
     /// Date and time when this actor/role was last updated.
+    ///
+    /// Assumed to be UTC, see [Dates and times](index.html#dates-and-times).
     #[serde(with = "ser::optional_date_time")]
     pub last_updated: Option<DateTime<Utc>>,
 }
@@ -383,6 +596,97 @@ impl Actor {
     pub fn image_url(&self) -> Result<Url> {
         urls::opt_image(&self.image)
     }
+
+    /// Returns `image_added` as sent by the API, without a time zone.
+    pub fn image_added_naive(&self) -> Option<NaiveDateTime> {
+        self.image_added.map(|dt| dt.naive_utc())
+    }
+
+    /// Returns `last_updated` as sent by the API, without a time zone.
+    pub fn last_updated_naive(&self) -> Option<NaiveDateTime> {
+        self.last_updated.map(|dt| dt.naive_utc())
+    }
+}
+
+/// Series data along with its actors, returned by [`Client::series_detail`].
+///
+/// See linked method for more info.
+///
+/// [`Client::series_detail`]: ../client/struct.Client.html#method.series_detail
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct SeriesDetail {
+    /// The series.
+    pub series: Series,
+    /// Actors playing in the series.
+    pub actors: Vec<Actor>,
+}
+
+/// A series' season with all its episodes, returned by [`Client::season`].
+///
+/// See linked method for more info.
+///
+/// [`Client::season`]: ../client/struct.Client.html#method.season
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Season {
+    /// Season number, `0` for specials.
+    pub number: u16,
+    /// Episodes of the season, sorted by aired episode number.
+    pub episodes: Vec<Episode>,
+    /// Number of episodes in the season.
+    pub episode_count: usize,
+}
+
+/// An episode with the episodes aired before and after it, returned by
+/// [`Client::episode_with_neighbors`].
+///
+/// See linked method for more info.
+///
+/// [`Client::episode_with_neighbors`]: ../client/struct.Client.html#method.episode_with_neighbors
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct EpisodeNeighbors {
+    /// The requested episode.
+    pub current: Episode,
+    /// The episode aired before, if any.
+    pub previous: Option<Episode>,
+    /// The episode aired after, if any.
+    pub next: Option<Episode>,
+}
+
+/// Translation coverage of a series in a language, returned by
+/// [`Client::series_language_coverage`].
+///
+/// See linked method for more info.
+///
+/// [`Client::series_language_coverage`]: ../client/struct.Client.html#method.series_language_coverage
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Coverage {
+    /// Number of episodes of the series.
+    pub total_episodes: usize,
+    /// Number of episodes with a name in the language.
+    pub episodes_with_localized_name: usize,
+    /// Whether the series name is available in the language.
+    pub series_name_localized: bool,
+}
+
+/// Series data along with whether its localized fields are actually English
+/// fallbacks, returned by [`Client::series_localized_checked`].
+///
+/// See linked method for more info.
+///
+/// [`Client::series_localized_checked`]: ../client/struct.Client.html#method.series_localized_checked
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct LocalizedSeries {
+    /// Series data in the client's language.
+    pub series: Series,
+    /// Whether `series_name` is the English name instead of a translation.
+    pub name_is_fallback: bool,
+    /// Whether `overview` is the English overview instead of a translation.
+    pub overview_is_fallback: bool,
 }
 
 /// Episode data returned by [`Client::series_episodes`],
@@ -401,9 +705,14 @@ pub struct Episode {
     /// ID of the episode.
     pub id: EpisodeID,
     /// Season that episode is part of.
+    #[serde(default, deserialize_with = "ser::optional_string_or_int")]
     pub aired_season: Option<u32>,
     /// Episode season ID.
-    #[serde(rename = "airedSeasonID")]
+    #[serde(
+        rename = "airedSeasonID",
+        default,
+        deserialize_with = "ser::optional_string_or_int"
+    )]
     pub aired_season_id: Option<u32>,
     /// Episode number in season.
     pub aired_episode_number: u32,
@@ -465,6 +774,8 @@ pub struct Episode {
     /// Author of episode image.
     pub thumb_author: Option<u32>,
     /// Date and time image was added.
+    ///
+    /// Assumed to be UTC, see [Dates and times](index.html#dates-and-times).
     #[serde(with = "ser::optional_date_time")]
     pub thumb_added: Option<DateTime<Utc>>,
     /// Image width.
@@ -480,6 +791,9 @@ pub struct Episode {
     #[serde(deserialize_with = "ser::optional_float")]
     pub site_rating: Option<f32>,
     /// Number of rating votes.
+    ///
+    /// Missing or `null` counts are deserialized as `0`.
+    #[serde(default, deserialize_with = "ser::null_as_default")]
     pub site_rating_count: u32,
     /// Is this episode a movie?
     #[serde(with = "ser::int_bool")]
@@ -494,6 +808,34 @@ impl Episode {
     pub fn filename_url(&self) -> Result<Url> {
         urls::opt_image(&self.filename)
     }
+
+    /// Returns `thumb_added` as sent by the API, without a time zone.
+    pub fn thumb_added_naive(&self) -> Option<NaiveDateTime> {
+        self.thumb_added.map(|dt| dt.naive_utc())
+    }
+
+    /// Deserialize an episode from JSON data, as returned by the API in the
+    /// `data` field of the `/episodes/{id}` response.
+    ///
+    /// Works like [`Series::from_value`].
+    ///
+    /// # Errors
+    /// Will fail if the JSON data is not a valid episode.
+    ///
+    /// [`Series::from_value`]: struct.Series.html#method.from_value
+    pub fn from_value(value: serde_json::Value) -> Result<Self> {
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Returns `true` if the episode's `first_aired` date is today or earlier.
+    ///
+    /// Dates are compared in UTC. Episodes with no `first_aired` date have
+    /// not aired.
+    pub fn has_aired(&self) -> bool {
+        self.first_aired
+            .map(|d| midnight_utc(d) <= Utc::now())
+            .unwrap_or(false)
+    }
 }
 
 /// Episode language info.
@@ -522,10 +864,32 @@ pub struct EpisodePage<E = Episode> {
     pub episodes: Vec<E>,
     #[serde(skip)]
     pub(crate) series_id: SeriesID,
+    #[serde(skip)]
+    pub(crate) requested_page: Option<u16>,
     links: PageLinks,
 }
 
 impl<E> EpisodePage<E> {
+    /// Returns the number of episodes on this page.
+    pub fn len(&self) -> usize {
+        self.episodes.len()
+    }
+
+    /// Returns `true` if this page has no episodes.
+    pub fn is_empty(&self) -> bool {
+        self.episodes.is_empty()
+    }
+
+    /// The page number that was requested to get this page.
+    ///
+    /// Always available for pages returned by [`Client::series_episodes`],
+    /// `None` if the page was deserialized in other ways.
+    ///
+    /// [`Client::series_episodes`]: ../client/struct.Client.html#method.series_episodes
+    pub fn requested_page(&self) -> Option<u16> {
+        self.requested_page
+    }
+
     /// Generate `EpisodeParams` to fetch the next page with
     /// [`Client::series_episodes`].
     ///
@@ -561,6 +925,24 @@ impl<E> EpisodePage<E> {
     }
 }
 
+impl<E> IntoIterator for EpisodePage<E> {
+    type Item = E;
+    type IntoIter = std::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.episodes.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a EpisodePage<E> {
+    type Item = &'a E;
+    type IntoIter = std::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.episodes.iter()
+    }
+}
+
 /// Struct used for queried episode pagination returned by
 /// [`Client::series_episodes_query`].
 ///
@@ -581,10 +963,33 @@ pub struct EpisodeQueryPage<E = Episode> {
     pub(crate) series_id: SeriesID,
     #[serde(skip)]
     pub(crate) query: EpisodeQuery,
+    #[serde(skip)]
+    pub(crate) requested_page: Option<u16>,
     links: PageLinks,
 }
 
 impl<E> EpisodeQueryPage<E> {
+    /// Returns the number of episodes on this page.
+    pub fn len(&self) -> usize {
+        self.episodes.len()
+    }
+
+    /// Returns `true` if this page has no episodes.
+    pub fn is_empty(&self) -> bool {
+        self.episodes.is_empty()
+    }
+
+    /// The page number that was requested to get this page.
+    ///
+    /// Always available for pages returned by
+    /// [`Client::series_episodes_query`], `None` if the page was deserialized
+    /// in other ways.
+    ///
+    /// [`Client::series_episodes_query`]: ../client/struct.Client.html#method.series_episodes_query
+    pub fn requested_page(&self) -> Option<u16> {
+        self.requested_page
+    }
+
     /// Generate `EpisodeQueryParams` to fetch the next page of query results
     /// with [`Client::series_episodes_query`].
     ///
@@ -624,6 +1029,24 @@ impl<E> EpisodeQueryPage<E> {
     }
 }
 
+impl<E> IntoIterator for EpisodeQueryPage<E> {
+    type Item = E;
+    type IntoIter = std::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.episodes.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a EpisodeQueryPage<E> {
+    type Item = &'a E;
+    type IntoIter = std::slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.episodes.iter()
+    }
+}
+
 /// Struct used for page links in paginated API results.
 #[derive(Clone, Debug, PartialEq, Deserialize)]
 pub struct PageLinks {
@@ -635,11 +1058,15 @@ pub struct PageLinks {
 
 impl PageLinks {
     fn current_page(&self) -> u16 {
-        match (self.next, self.prev) {
-            (Some(n), _) => n - 1,
-            (None, Some(p)) => p + 1,
-            _ => self.first,
-        }
+        let page = match (self.next, self.prev) {
+            (Some(n), _) => n.saturating_sub(1),
+            (None, Some(p)) => p.saturating_add(1),
+            // a single page, or the API omitted the links of the last page
+            (None, None) => self.last,
+        };
+
+        // inconsistent links could point outside of the first..=last range
+        page.max(self.first).min(self.last.max(self.first))
     }
 }
 
@@ -651,6 +1078,8 @@ pub trait Pagination {
     fn links(&self) -> &PageLinks;
 
     /// The current page.
+    ///
+    /// Inferred from the page links by default.
     fn current_page(&self) -> u16 {
         self.links().current_page()
     }
@@ -674,18 +1103,53 @@ pub trait Pagination {
     fn prev_page(&self) -> Option<u16> {
         self.links().prev
     }
+
+    /// Returns `true` if there is a next page.
+    fn has_next(&self) -> bool {
+        self.next_page().is_some()
+    }
+
+    /// Returns `true` if there is a previous page.
+    fn has_prev(&self) -> bool {
+        self.prev_page().is_some()
+    }
+
+    /// The total number of pages.
+    ///
+    /// Same as [`last_page`](#method.last_page), since pages are numbered
+    /// starting from `1`.
+    fn total_pages(&self) -> u16 {
+        self.last_page()
+    }
+
+    /// All the page numbers, from the first page to the last one.
+    fn pages(&self) -> RangeInclusive<u16> {
+        self.first_page()..=self.last_page()
+    }
 }
 
 impl<E> Pagination for EpisodePage<E> {
     fn links(&self) -> &PageLinks {
         &self.links
     }
+
+    /// The requested page if known, otherwise inferred from the page links.
+    fn current_page(&self) -> u16 {
+        self.requested_page
+            .unwrap_or_else(|| self.links.current_page())
+    }
 }
 
 impl<E> Pagination for EpisodeQueryPage<E> {
     fn links(&self) -> &PageLinks {
         &self.links
     }
+
+    /// The requested page if known, otherwise inferred from the page links.
+    fn current_page(&self) -> u16 {
+        self.requested_page
+            .unwrap_or_else(|| self.links.current_page())
+    }
 }
 
 /// Episode summary data returned by [`Client::series_episodes_summary`].
@@ -709,6 +1173,28 @@ pub struct EpisodeSummary {
     pub dvd_episodes: u32,
 }
 
+impl EpisodeSummary {
+    /// Returns the number of aired seasons, optionally excluding the
+    /// specials season (`"0"`).
+    pub fn season_count(&self, include_specials: bool) -> usize {
+        self.aired_seasons
+            .iter()
+            .filter(|s| include_specials || s.trim().parse::<u16>() != Ok(0))
+            .count()
+    }
+
+    /// Returns the highest aired season number.
+    ///
+    /// Returns `None` if there are no aired seasons. Season numbers that
+    /// cannot be parsed are ignored.
+    pub fn highest_season(&self) -> Option<u16> {
+        self.aired_seasons
+            .iter()
+            .filter_map(|s| s.trim().parse().ok())
+            .max()
+    }
+}
+
 /// Series image count data returned by [`Client::series_images`].
 ///
 /// See linked method for more info.
@@ -829,5 +1315,93 @@ pub struct SeriesUpdate {
     pub last_updated: DateTime<Utc>,
 }
 
+/// Rating given by the user to a series, episode or image, returned by
+/// [`Client::user_ratings`].
+///
+/// [`Client::user_ratings`]: ../client/struct.Client.html#method.user_ratings
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct Rating {
+    /// Type of the rated item.
+    pub rating_type: RatingItemType,
+    /// ID of the rated item.
+    pub rating_item_id: u32,
+    /// The rating, from `1` to `10`.
+    pub rating: u8,
+}
+
+// FNV-1a hasher used for content hashes.
+//
+// Unlike `DefaultHasher`, its output is not tied to the std implementation,
+// and integers are always written as little endian 64 bit values so that the
+// result doesn't depend on the platform.
+struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= u64::from(*b);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write_u64(i.into());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(i.into());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+}
+
+/// IDs of the series and movies updated since a given time, returned by
+/// [`Client::changes_since`].
+///
+/// See linked method for more info.
+///
+/// [`Client::changes_since`]: ../client/struct.Client.html#method.changes_since
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Changes {
+    /// IDs of the updated series, in ascending order.
+    pub series: Vec<SeriesID>,
+    /// IDs of the updated movies, in ascending order.
+    pub movies: Vec<MovieID>,
+}
+
+fn midnight_utc(date: NaiveDate) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).expect("invalid midnight time"))
+}
+
 #[cfg(test)]
 mod tests;
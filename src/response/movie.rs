@@ -8,6 +8,9 @@ use crate::error::Result;
 use crate::serialization as ser;
 use crate::urls;
 
+// `source_name` of IMDb remote IDs
+const IMDB_SOURCE_NAME: &str = "IMDB";
+
 /// Custom type used for [`Movie`](./struct.Movie.html) ids.
 #[derive(
     Clone, Copy, Debug, Default, Hash, PartialEq, PartialOrd, Ord, Eq, Deserialize, Serialize,
@@ -26,6 +29,12 @@ impl From<u32> for MovieID {
     }
 }
 
+impl From<&Movie> for MovieID {
+    fn from(m: &Movie) -> MovieID {
+        m.id
+    }
+}
+
 /// Movie data returned by [`Client::movie`].
 ///
 /// [`Client::movie`]: ../client/struct.Client.html#method.movie
@@ -37,6 +46,9 @@ pub struct Movie {
     /// Movie URL.
     pub url: String,
     /// Movie runtime, in minutes.
+    ///
+    /// Unknown runtimes (missing, `null` or empty) are deserialized as `0`.
+    #[serde(default, deserialize_with = "ser::string_or_int_or_default")]
     pub runtime: u16,
     /// Movie genres.
     pub genres: Vec<Genre>,
@@ -54,6 +66,62 @@ pub struct Movie {
     pub people: People,
 }
 
+impl Movie {
+    /// Returns the distinct artwork types of the movie's artworks, in the
+    /// order they first appear.
+    pub fn artwork_types(&self) -> Vec<&str> {
+        let mut types: Vec<&str> = Vec::new();
+
+        for artwork in &self.artworks {
+            if !types.contains(&artwork.artwork_type.as_str()) {
+                types.push(&artwork.artwork_type);
+            }
+        }
+
+        types
+    }
+
+    /// Returns the movie's artworks of the given type.
+    pub fn artworks_of_type<'a>(
+        &'a self,
+        artwork_type: &'a str,
+    ) -> impl Iterator<Item = &'a Artwork> {
+        self.artworks
+            .iter()
+            .filter(move |a| a.artwork_type == artwork_type)
+    }
+
+    /// Returns the movie's ID on the remote source with the given name.
+    ///
+    /// Source names are compared ignoring case.
+    pub fn remote_id(&self, source_name: &str) -> Option<&str> {
+        self.remoteids
+            .iter()
+            .find(|r| r.source_name.eq_ignore_ascii_case(source_name))
+            .map(|r| r.id.as_str())
+    }
+
+    /// Returns the movie's IMDb ID, if known.
+    ///
+    /// The API does not support looking up movies by their IMDb ID, so this
+    /// can be used to match movies fetched with [`Client::movie`] against
+    /// known IMDb IDs. See also [`has_imdb_id`](#method.has_imdb_id).
+    ///
+    /// [`Client::movie`]: ../client/struct.Client.html#method.movie
+    pub fn imdb_id(&self) -> Option<&str> {
+        self.remote_id(IMDB_SOURCE_NAME)
+    }
+
+    /// Returns `true` if the movie's IMDb ID is `imdb_id`.
+    ///
+    /// IDs are compared ignoring case and surrounding whitespace.
+    pub fn has_imdb_id(&self, imdb_id: &str) -> bool {
+        self.imdb_id()
+            .filter(|id| id.trim().eq_ignore_ascii_case(imdb_id.trim()))
+            .is_some()
+    }
+}
+
 /// Movie genre data.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[cfg_attr(test, derive(Default))]
@@ -139,6 +139,35 @@ fn episode_urls_errors() {
     assert_missing_image_err(e.filename_url());
 }
 
+#[test]
+fn series_content_hash() {
+    let s = Series {
+        id: SeriesID(10),
+        series_name: Some("Test".to_string()),
+        overview: Some("testing".to_string()),
+        site_rating: Some(7.5),
+
+        ..Default::default()
+    };
+
+    assert_eq!(s.content_hash(), s.clone().content_hash());
+    assert_eq!(s.content_hash(), 0x9025_ddb0_1741_11af);
+
+    let updated = Series {
+        last_updated: Some(now_round_seconds()),
+        ..s.clone()
+    };
+
+    assert_eq!(s.content_hash(), updated.content_hash());
+
+    let changed = Series {
+        overview: Some("tested".to_string()),
+        ..s.clone()
+    };
+
+    assert_ne!(s.content_hash(), changed.content_hash());
+}
+
 #[test]
 fn page_links_current_page() {
     let mut pl = PageLinks {
@@ -176,6 +205,108 @@ fn page_links_current_page() {
     assert_eq!(pl.current_page(), 1);
 }
 
+#[test]
+fn page_links_current_page_missing_links() {
+    let pl = PageLinks {
+        first: 1,
+        last: 5,
+        next: None,
+        prev: None,
+    };
+
+    // the API omits the links on the last page of some results
+    assert_eq!(pl.current_page(), 5);
+
+    let ep: EpisodePage = EpisodePage {
+        episodes: vec![Episode::default(); 3],
+        series_id: SeriesID(1),
+        requested_page: None,
+        links: pl,
+    };
+
+    assert_eq!(ep.current_page(), 5);
+    assert_eq!(ep.next_page_params(), None);
+}
+
+#[test]
+fn page_links_current_page_inconsistent() {
+    let mut pl = PageLinks {
+        first: 1,
+        last: 20,
+        next: None,
+        prev: Some(20),
+    };
+
+    // prev == last
+    assert_eq!(pl.current_page(), 20);
+
+    pl = PageLinks {
+        next: None,
+        prev: Some(u16::MAX),
+        ..pl
+    };
+
+    assert_eq!(pl.current_page(), 20);
+
+    pl = PageLinks {
+        next: Some(30),
+        prev: None,
+        ..pl
+    };
+
+    assert_eq!(pl.current_page(), 20);
+
+    pl = PageLinks {
+        next: Some(0),
+        prev: None,
+        ..pl
+    };
+
+    assert_eq!(pl.current_page(), 1);
+
+    pl = PageLinks {
+        first: 5,
+        last: 2,
+        next: None,
+        prev: None,
+    };
+
+    assert_eq!(pl.current_page(), 5);
+}
+
+#[test]
+fn pagination_helpers() {
+    let mut ep: EpisodePage = EpisodePage {
+        episodes: Vec::new(),
+        series_id: SeriesID(1),
+        requested_page: None,
+        links: PageLinks {
+            first: 1,
+            last: 10,
+            next: Some(5),
+            prev: Some(3),
+        },
+    };
+
+    assert!(ep.has_next());
+    assert!(ep.has_prev());
+    assert_eq!(ep.total_pages(), 10);
+    assert_eq!(ep.pages(), 1..=10);
+    assert_eq!(ep.pages().count(), 10);
+
+    ep.links = PageLinks {
+        first: 1,
+        last: 1,
+        next: None,
+        prev: None,
+    };
+
+    assert!(!ep.has_next());
+    assert!(!ep.has_prev());
+    assert_eq!(ep.total_pages(), 1);
+    assert_eq!(ep.pages().collect::<Vec<_>>(), vec![1]);
+}
+
 #[test]
 fn episode_page_params_generation() {
     let sid = SeriesID(123);
@@ -183,6 +314,7 @@ fn episode_page_params_generation() {
     let mut ep = EpisodePage {
         episodes: vec![Episode::default()],
         series_id: sid,
+        requested_page: None,
         links: PageLinks {
             first: 1,
             last: 10,
@@ -207,6 +339,45 @@ fn episode_page_params_generation() {
     assert_eq!(ep.prev_page_params(), None);
 }
 
+#[test]
+fn episode_pages_current_page() {
+    let links = PageLinks {
+        first: 1,
+        last: 3,
+        next: Some(3),
+        prev: Some(1),
+    };
+
+    let mut ep = EpisodePage::<Episode> {
+        episodes: vec![],
+        series_id: SeriesID(1),
+        requested_page: None,
+        links: links.clone(),
+    };
+
+    assert_eq!(ep.requested_page(), None);
+    assert_eq!(ep.current_page(), 2);
+
+    ep.requested_page = Some(5);
+    assert_eq!(ep.requested_page(), Some(5));
+    assert_eq!(ep.current_page(), 5);
+
+    let mut eqp = EpisodeQueryPage::<Episode> {
+        episodes: vec![],
+        series_id: SeriesID(1),
+        query: params::EpisodeQuery::default(),
+        requested_page: None,
+        links,
+    };
+
+    assert_eq!(eqp.requested_page(), None);
+    assert_eq!(eqp.current_page(), 2);
+
+    eqp.requested_page = Some(5);
+    assert_eq!(eqp.requested_page(), Some(5));
+    assert_eq!(eqp.current_page(), 5);
+}
+
 #[test]
 fn episode_query_page_params_generation() {
     let sid = SeriesID(321);
@@ -218,12 +389,14 @@ fn episode_query_page_params_generation() {
         dvd_season: Some(14),
         dvd_episode: Some(15),
         imdb_id: Some("imdb".to_string()),
+        first_aired: NaiveDate::from_ymd_opt(2016, 11, 6),
     };
 
     let mut eqp = EpisodeQueryPage {
         episodes: vec![Episode::default()],
         series_id: sid,
         query: query.clone(),
+        requested_page: None,
         links: PageLinks {
             first: 1,
             last: 15,
@@ -248,6 +421,66 @@ fn episode_query_page_params_generation() {
     assert_eq!(eqp.prev_page_query_params(), None);
 }
 
+#[test]
+fn episode_pages_into_iter() {
+    let episodes: Vec<Episode> = (1..=3)
+        .map(|id| Episode {
+            id: EpisodeID(id),
+            ..Default::default()
+        })
+        .collect();
+    let links = PageLinks {
+        first: 1,
+        last: 1,
+        next: None,
+        prev: None,
+    };
+
+    let ep = EpisodePage {
+        episodes: episodes.clone(),
+        series_id: SeriesID(1),
+        requested_page: None,
+        links: links.clone(),
+    };
+
+    assert_eq!(ep.len(), 3);
+    assert!(!ep.is_empty());
+
+    let ids: Vec<EpisodeID> = (&ep).into_iter().map(|e| e.id).collect();
+    assert_eq!(ids, vec![EpisodeID(1), EpisodeID(2), EpisodeID(3)]);
+    assert_eq!(ep.into_iter().collect::<Vec<_>>(), episodes);
+
+    let eqp = EpisodeQueryPage {
+        episodes: episodes.clone(),
+        series_id: SeriesID(1),
+        query: params::EpisodeQuery::default(),
+        requested_page: None,
+        links,
+    };
+
+    assert_eq!(eqp.len(), 3);
+    assert!(!eqp.is_empty());
+
+    let ids: Vec<EpisodeID> = (&eqp).into_iter().map(|e| e.id).collect();
+    assert_eq!(ids, vec![EpisodeID(1), EpisodeID(2), EpisodeID(3)]);
+    assert_eq!(eqp.into_iter().collect::<Vec<_>>(), episodes);
+
+    let empty: EpisodePage = EpisodePage {
+        episodes: Vec::new(),
+        series_id: SeriesID(1),
+        requested_page: None,
+        links: PageLinks {
+            first: 1,
+            last: 1,
+            next: None,
+            prev: None,
+        },
+    };
+
+    assert_eq!(empty.len(), 0);
+    assert!(empty.is_empty());
+}
+
 #[test]
 fn image_urls() -> Result<()> {
     let i = Image {
@@ -317,6 +550,355 @@ fn person_urls_errors() {
     }
 }
 
+#[test]
+fn from_value() -> Result<()> {
+    let series = Series {
+        id: SeriesID(10),
+        series_name: Some("Test".to_string()),
+        ..Default::default()
+    };
+
+    let mut value = json::to_value(&series)?;
+    value["banner"] = json::Value::String(String::new());
+
+    assert_eq!(Series::from_value(value)?, series);
+
+    let episode = Episode {
+        id: EpisodeID(20),
+        ..Default::default()
+    };
+
+    let mut value = json::to_value(&episode)?;
+    value["filename"] = json::Value::String(String::new());
+
+    assert_eq!(Episode::from_value(value)?, episode);
+
+    match Series::from_value(json::json!({ "id": "not a number" })).unwrap_err() {
+        Error::InvalidJSON(_) => {}
+        e => panic!("Wrong error kind: expected InvalidJSON, got {:?}", e),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn series_first_aired_datetime() {
+    let mut series = Series {
+        first_aired: Some(NaiveDate::from_ymd_opt(2016, 11, 6).unwrap()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        series.first_aired_datetime(),
+        Some(Utc.with_ymd_and_hms(2016, 11, 6, 0, 0, 0).unwrap())
+    );
+
+    series.first_aired = None;
+
+    assert_eq!(series.first_aired_datetime(), None);
+}
+
+#[test]
+fn episode_has_aired() {
+    let today = Utc::now().date_naive();
+
+    let mut episode = Episode {
+        first_aired: Some(today.pred_opt().unwrap()),
+        ..Default::default()
+    };
+    assert!(episode.has_aired());
+
+    episode.first_aired = Some(today);
+    assert!(episode.has_aired());
+
+    episode.first_aired = Some(today.succ_opt().unwrap());
+    assert!(!episode.has_aired());
+
+    episode.first_aired = None;
+    assert!(!episode.has_aired());
+}
+
+#[test]
+fn episode_season_string_or_int() -> Result<()> {
+    let mut value = json::to_value(Episode::default())?;
+
+    value["airedSeason"] = json::json!(2);
+    value["airedSeasonID"] = json::json!(1234);
+
+    let episode: Episode = json::from_value(value.clone())?;
+    assert_eq!(episode.aired_season, Some(2));
+    assert_eq!(episode.aired_season_id, Some(1234));
+
+    value["airedSeason"] = json::json!("2");
+    value["airedSeasonID"] = json::json!("1234");
+
+    let episode: Episode = json::from_value(value.clone())?;
+    assert_eq!(episode.aired_season, Some(2));
+    assert_eq!(episode.aired_season_id, Some(1234));
+
+    value["airedSeason"] = json::json!("");
+    value["airedSeasonID"] = json::Value::Null;
+
+    let episode: Episode = json::from_value(value.clone())?;
+    assert_eq!(episode.aired_season, None);
+    assert_eq!(episode.aired_season_id, None);
+
+    value["airedSeason"] = json::json!("two");
+
+    assert!(json::from_value::<Episode>(value).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn site_rating_count_null() -> Result<()> {
+    let mut value = json::to_value(Series::default())?;
+
+    value["siteRatingCount"] = json::Value::Null;
+    assert_eq!(
+        json::from_value::<Series>(value.clone())?.site_rating_count,
+        0
+    );
+
+    value.as_object_mut().unwrap().remove("siteRatingCount");
+    assert_eq!(json::from_value::<Series>(value)?.site_rating_count, 0);
+
+    let mut value = json::to_value(Episode::default())?;
+
+    value["siteRatingCount"] = json::Value::Null;
+    assert_eq!(
+        json::from_value::<Episode>(value.clone())?.site_rating_count,
+        0
+    );
+
+    value["siteRatingCount"] = json::json!(12);
+    assert_eq!(
+        json::from_value::<Episode>(value.clone())?.site_rating_count,
+        12
+    );
+
+    value.as_object_mut().unwrap().remove("siteRatingCount");
+    assert_eq!(json::from_value::<Episode>(value)?.site_rating_count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn movie_runtime_null_or_string() -> Result<()> {
+    let runtime = |runtime: json::Value| {
+        json::from_value::<Movie>(movie_json(json::json!({ "runtime": runtime })))
+    };
+
+    assert_eq!(runtime(json::json!(142))?.runtime, 142);
+    assert_eq!(runtime(json::json!("142"))?.runtime, 142);
+    assert_eq!(runtime(json::Value::Null)?.runtime, 0);
+    assert_eq!(runtime(json::json!(""))?.runtime, 0);
+    assert!(runtime(json::json!("long")).is_err());
+
+    let mut value = movie_json(json::json!({}));
+
+    value.as_object_mut().unwrap().remove("runtime");
+    assert_eq!(json::from_value::<Movie>(value)?.runtime, 0);
+
+    Ok(())
+}
+
+#[test]
+fn movie_artwork_types() -> Result<()> {
+    let artwork = |id: &str, artwork_type: &str| Artwork {
+        id: id.to_string(),
+        artwork_type: artwork_type.to_string(),
+        ..Default::default()
+    };
+
+    let mut movie: Movie = json::from_value(movie_json(json::json!({})))?;
+
+    assert!(movie.artwork_types().is_empty());
+
+    movie.artworks = vec![
+        artwork("1", "Poster"),
+        artwork("2", "Background"),
+        artwork("3", "Poster"),
+        artwork("4", "Banner"),
+    ];
+
+    assert_eq!(
+        movie.artwork_types(),
+        vec!["Poster", "Background", "Banner"]
+    );
+    assert_eq!(
+        movie
+            .artworks_of_type("Poster")
+            .map(|a| a.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["1", "3"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn movie_imdb_id() -> Result<()> {
+    let remote_id = |id: &str, source_name: &str| RemoteID {
+        id: id.to_string(),
+        source_id: 2,
+        source_name: source_name.to_string(),
+        url: String::new(),
+    };
+
+    let mut movie: Movie = json::from_value(movie_json(json::json!({})))?;
+
+    assert_eq!(movie.imdb_id(), None);
+    assert!(!movie.has_imdb_id("tt0133093"));
+
+    movie.remoteids = vec![
+        remote_id("603", "TheMovieDB.com"),
+        remote_id("tt0133093", "IMDB"),
+    ];
+
+    assert_eq!(movie.imdb_id(), Some("tt0133093"));
+    assert_eq!(movie.remote_id("themoviedb.com"), Some("603"));
+    assert_eq!(movie.remote_id("EIDR"), None);
+    assert!(movie.has_imdb_id("tt0133093"));
+    assert!(movie.has_imdb_id(" TT0133093 "));
+    assert!(!movie.has_imdb_id("tt0234215"));
+
+    Ok(())
+}
+
+#[test]
+fn naive_date_times() -> Result<()> {
+    let mut value = json::to_value(Series::default())?;
+    value["added"] = json::json!("2017-06-28 10:48:00");
+
+    let series: Series = json::from_value(value)?;
+
+    let naive = NaiveDate::from_ymd_opt(2017, 6, 28)
+        .unwrap()
+        .and_hms_opt(10, 48, 0)
+        .unwrap();
+
+    assert_eq!(series.added_naive(), Some(naive));
+    assert_eq!(
+        series.added.map(|dt| dt.to_rfc3339()),
+        Some("2017-06-28T10:48:00+00:00".to_string())
+    );
+
+    let actor = Actor {
+        image_added: series.added,
+        ..Default::default()
+    };
+
+    assert_eq!(actor.image_added_naive(), Some(naive));
+    assert_eq!(actor.last_updated_naive(), None);
+
+    let episode = Episode {
+        thumb_added: series.added,
+        ..Default::default()
+    };
+
+    assert_eq!(episode.thumb_added_naive(), Some(naive));
+
+    Ok(())
+}
+
+#[test]
+fn episode_summary_counts_string_or_int() -> Result<()> {
+    let mut value = json::json!({
+        "airedSeasons": ["1", "2"],
+        "airedEpisodes": "18",
+        "dvdSeasons": [],
+        "dvdEpisodes": "0",
+    });
+
+    let summary: EpisodeSummary = json::from_value(value.clone())?;
+    assert_eq!(summary.aired_episodes, 18);
+    assert_eq!(summary.dvd_episodes, 0);
+
+    value["airedEpisodes"] = json::json!(18);
+    value["dvdEpisodes"] = json::json!(12);
+
+    let summary: EpisodeSummary = json::from_value(value.clone())?;
+    assert_eq!(summary.aired_episodes, 18);
+    assert_eq!(summary.dvd_episodes, 12);
+
+    value["airedEpisodes"] = json::json!("eighteen");
+    assert!(json::from_value::<EpisodeSummary>(value.clone()).is_err());
+
+    value["airedEpisodes"] = json::Value::Null;
+    assert!(json::from_value::<EpisodeSummary>(value).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn episode_summary_seasons() {
+    let mut summary = EpisodeSummary {
+        aired_seasons: vec!["2".to_string(), "0".to_string(), "10".to_string()],
+        aired_episodes: 20,
+        dvd_seasons: vec![],
+        dvd_episodes: 0,
+    };
+
+    assert_eq!(summary.season_count(true), 3);
+    assert_eq!(summary.season_count(false), 2);
+    assert_eq!(summary.highest_season(), Some(10));
+
+    summary.aired_seasons = vec!["0".to_string()];
+
+    assert_eq!(summary.season_count(false), 0);
+    assert_eq!(summary.highest_season(), Some(0));
+
+    summary.aired_seasons = vec![];
+
+    assert_eq!(summary.season_count(true), 0);
+    assert_eq!(summary.highest_season(), None);
+}
+
+#[test]
+fn series_network_or_unknown() {
+    let mut series = Series {
+        network: Some("BBC One".to_string()),
+        ..Default::default()
+    };
+
+    assert_eq!(series.network_or_unknown(), "BBC One");
+
+    series.network = Some(" ".to_string());
+    assert_eq!(series.network_or_unknown(), "Unknown");
+
+    series.network = None;
+    assert_eq!(series.network_or_unknown(), "Unknown");
+}
+
+#[test]
+fn series_content_rating() {
+    let cases = vec![
+        (Some("TV-Y"), Some(ContentRating::TVY)),
+        (Some("TV-Y7"), Some(ContentRating::TVY7)),
+        (Some("TV-Y7-FV"), Some(ContentRating::TVY7)),
+        (Some("TV-G"), Some(ContentRating::TVG)),
+        (Some("TV-PG"), Some(ContentRating::TVPG)),
+        (Some("TV-14"), Some(ContentRating::TV14)),
+        (Some("TV-MA"), Some(ContentRating::TVMA)),
+        (Some("NR"), None),
+        (None, None),
+    ];
+
+    for (rating, expected) in cases {
+        let series = Series {
+            rating: rating.map(str::to_string),
+            ..Default::default()
+        };
+
+        assert_eq!(series.content_rating(), expected);
+    }
+
+    assert!(ContentRating::TVY < ContentRating::TVY7);
+    assert!(ContentRating::TVPG < ContentRating::TV14);
+    assert!(ContentRating::TV14 < ContentRating::TVMA);
+}
+
 #[test]
 fn types_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
@@ -327,6 +909,7 @@ fn types_send_sync() {
     assert_send_sync::<Series>();
     assert_send_sync::<FilteredSeries>();
     assert_send_sync::<SeriesStatus>();
+    assert_send_sync::<ContentRating>();
     assert_send_sync::<Actor>();
     assert_send_sync::<Episode>();
     assert_send_sync::<EpisodeLanguage>();
@@ -339,6 +922,12 @@ fn types_send_sync() {
     assert_send_sync::<ImageRatingsInfo>();
     assert_send_sync::<ImageQueryKey>();
     assert_send_sync::<SeriesUpdate>();
+    assert_send_sync::<SeriesDetail>();
+    assert_send_sync::<Changes>();
+    assert_send_sync::<Season>();
+    assert_send_sync::<SearchResponse>();
+    assert_send_sync::<Coverage>();
+    assert_send_sync::<LocalizedSeries>();
     assert_send_sync::<MovieID>();
     assert_send_sync::<Movie>();
     assert_send_sync::<Genre>();
@@ -357,7 +946,7 @@ fn serde() -> Result<()> {
     let ss = SearchSeries {
         aliases: vec!["test".to_string(), "testing".to_string()],
         banner: Some("banner".to_string()),
-        first_aired: Some(NaiveDate::from_ymd(2000, 10, 30)),
+        first_aired: Some(NaiveDate::from_ymd_opt(2000, 10, 30).unwrap()),
         id: SeriesID(10),
         network: Some("Test".to_string()),
         overview: Some("test".to_string()),
@@ -375,13 +964,13 @@ fn serde() -> Result<()> {
         added: Some(now_round_seconds()),
         added_by: Some(10),
         airs_day_of_week: Some("Monday".to_string()),
-        airs_time: Some(NaiveTime::from_hms(8, 30, 0)),
+        airs_time: Some(NaiveTime::from_hms_opt(8, 30, 0).unwrap()),
         aliases: vec!["test".to_string(), "testing".to_string()],
         season: "2".to_string(),
         banner: Some("banner".to_string()),
         poster: Some("poster".to_string()),
         fanart: Some("fanart".to_string()),
-        first_aired: Some(NaiveDate::from_ymd(2000, 7, 21)),
+        first_aired: Some(NaiveDate::from_ymd_opt(2000, 7, 21).unwrap()),
         genre: vec!["Drama".to_string(), "Comedy".to_string()],
         id: SeriesID(20),
         imdb_id: Some("tttest".to_string()),
@@ -409,13 +998,13 @@ fn serde() -> Result<()> {
         added: Some(now_round_seconds()),
         added_by: Some(21),
         airs_day_of_week: Some("Friday".to_string()),
-        airs_time: Some(NaiveTime::from_hms(9, 10, 00)),
+        airs_time: Some(NaiveTime::from_hms_opt(9, 10, 00).unwrap()),
         aliases: Some(vec!["filtered".to_string(), "test".to_string()]),
         season: Some("3".to_string()),
         banner: Some("filtered_banner".to_string()),
         poster: Some("filtered_poster".to_string()),
         fanart: Some("filtered_fanart".to_string()),
-        first_aired: Some(NaiveDate::from_ymd(2004, 1, 6)),
+        first_aired: Some(NaiveDate::from_ymd_opt(2004, 1, 6).unwrap()),
         genre: Some(vec!["Thriller".to_string()]),
         id: Some(SeriesID(321451)),
         imdb_id: Some("testtt".to_string()),
@@ -462,7 +1051,7 @@ fn serde() -> Result<()> {
         aired_season_id: Some(3),
         aired_episode_number: 4,
         episode_name: Some("Test".to_string()),
-        first_aired: Some(NaiveDate::from_ymd(2010, 3, 12)),
+        first_aired: Some(NaiveDate::from_ymd_opt(2010, 3, 12).unwrap()),
         guest_stars: vec!["Sirius".to_string(), "Pollux".to_string()],
         directors: vec!["Director Test".to_string()],
         writers: vec!["Ms. Writer".to_string()],
@@ -571,7 +1160,7 @@ fn serde() -> Result<()> {
         }],
         release_dates: vec![ReleaseDate {
             kind: "testing".to_string(),
-            date: NaiveDate::from_ymd(2011, 11, 1),
+            date: NaiveDate::from_ymd_opt(2011, 11, 1).unwrap(),
             country: "Testlandia".to_string(),
         }],
         artworks: vec![Artwork {
@@ -635,6 +1224,28 @@ fn check_episode_query_page_params(
     assert_eq!(query_params.query, *query);
 }
 
+// minimal movie data, with the given fields added or replaced
+fn movie_json(fields: json::Value) -> json::Value {
+    let mut value = json::json!({
+        "id": 412,
+        "url": "movie_url",
+        "runtime": 142,
+        "genres": [],
+        "translations": [],
+        "release_dates": [],
+        "artworks": [],
+        "trailers": [],
+        "remoteids": [],
+        "people": {},
+    });
+
+    for (key, field) in fields.as_object().expect("movie fields must be an object") {
+        value[key] = field.clone();
+    }
+
+    value
+}
+
 fn assert_missing_image_err<T>(result: Result<T>)
 where
     T: std::fmt::Debug,
@@ -649,7 +1260,7 @@ fn ser_deser<T>(t: &T) -> JSONResult<T>
 where
     T: DeserializeOwned + Serialize,
 {
-    Ok(json::from_slice(&json::to_vec(t)?)?)
+    json::from_slice(&json::to_vec(t)?)
 }
 
 fn assert_ser_deser<T>(t: &T)
@@ -660,6 +1271,6 @@ where
 
     assert_eq!(
         t,
-        &ser_deser(t).expect(&format!("failed to ser-deser {}", type_name::<T>()))
+        &ser_deser(t).unwrap_or_else(|_| panic!("failed to ser-deser {}", type_name::<T>()))
     );
 }
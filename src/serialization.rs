@@ -1,3 +1,5 @@
+use std::{fmt, str::FromStr};
+
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -28,6 +30,81 @@ where
     }
 }
 
+pub fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::deserialize(deserializer)?.unwrap_or_default())
+}
+
+pub fn optional_string_or_int<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: fmt::Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrInt<T> {
+        Int(T),
+        String(String),
+    }
+
+    match Option::deserialize(deserializer)? {
+        Some(StringOrInt::Int(i)) => Ok(Some(i)),
+        Some(StringOrInt::String(s)) if !s.is_empty() => {
+            s.parse().map(Some).map_err(serde::de::Error::custom)
+        }
+        _ => Ok(None),
+    }
+}
+
+pub fn string_or_int<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: fmt::Display,
+{
+    optional_string_or_int(deserializer)?
+        .ok_or_else(|| serde::de::Error::custom("expected a number, got null or empty string"))
+}
+
+// list of numbers sent either as strings or as ints, skipping empty strings
+pub fn string_or_int_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: fmt::Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrInt<T> {
+        Int(T),
+        String(String),
+    }
+
+    let items: Vec<StringOrInt<T>> = null_as_default(deserializer)?;
+
+    items
+        .into_iter()
+        .filter_map(|i| match i {
+            StringOrInt::Int(i) => Some(Ok(i)),
+            StringOrInt::String(s) if s.is_empty() => None,
+            StringOrInt::String(s) => Some(s.parse().map_err(serde::de::Error::custom)),
+        })
+        .collect()
+}
+
+pub fn string_or_int_or_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr + Default,
+    T::Err: fmt::Display,
+{
+    Ok(optional_string_or_int(deserializer)?.unwrap_or_default())
+}
+
 pub mod optional_naive_time {
     use super::*;
 
@@ -132,13 +209,12 @@ pub mod optional_date_time {
 pub mod u32_string {
     use super::*;
 
+    // also accepts integers, in case the API stops encoding them as strings
     pub fn deserialize<'de, D>(deserializer: D) -> Result<u32, D::Error>
     where
         D: Deserializer<'de>,
     {
-        String::deserialize(deserializer)?
-            .parse()
-            .map_err(serde::de::Error::custom)
+        string_or_int(deserializer)
     }
 
     #[allow(clippy::trivially_copy_pass_by_ref)]
@@ -160,7 +236,11 @@ pub mod optional_ts_seconds_date_time {
         let opt_secs: Option<i64> = Option::deserialize(deserializer)?;
 
         match opt_secs {
-            Some(secs) if secs != 0 => Ok(Some(Utc.timestamp(secs, 0))),
+            Some(secs) if secs != 0 => Utc
+                .timestamp_opt(secs, 0)
+                .single()
+                .map(Some)
+                .ok_or_else(|| serde::de::Error::custom("invalid timestamp")),
             _ => Ok(None),
         }
     }
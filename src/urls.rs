@@ -29,7 +29,7 @@ pub(crate) fn image(file_name: &str) -> Result<Url> {
 pub(crate) fn opt_image(file_name: &Option<String>) -> Result<Url> {
     match file_name {
         None => Err(Error::MissingImage),
-        Some(f) => image(&f),
+        Some(f) => image(f),
     }
 }
 
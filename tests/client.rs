@@ -3,7 +3,6 @@ use std::str::FromStr;
 use chrono::{Duration, Utc};
 use futures::future::join_all;
 use lazy_static::lazy_static;
-use reqwest;
 use tokio::sync::{Mutex, MutexGuard};
 use url::Url;
 
@@ -194,6 +193,19 @@ async fn series_episodes_query() {
     assert_eq!(*episode, *ISLANDS);
 }
 
+#[tokio::test]
+async fn series_episodes_query_params() {
+    let guard = get_client().await;
+    let client = guard.as_ref().unwrap();
+
+    let keys = client
+        .series_episodes_query_params(PEII.id)
+        .await
+        .expect("Error fetching series episodes query params");
+
+    assert!(keys.iter().any(|k| k == "airedSeason"));
+}
+
 #[tokio::test]
 async fn series_episode_summary() {
     let guard = get_client().await;
@@ -454,8 +466,8 @@ async fn get_client() -> MutexGuard<'static, Option<Client>> {
     let mut client = CLIENT.lock().await;
 
     if client.is_none() {
-        let api_key =
-            std::env::var(ENV_APIKEY).expect(&format!("Missing or invalid {} env var", ENV_APIKEY));
+        let api_key = std::env::var(ENV_APIKEY)
+            .unwrap_or_else(|_| panic!("Missing or invalid {} env var", ENV_APIKEY));
 
         *client = Some(
             Client::new(api_key)
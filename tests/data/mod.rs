@@ -103,7 +103,7 @@ lazy_static! {
     pub static ref PEII: TestSeries = TestSeries {
         id: SeriesID(318408),
         series_name: "Planet Earth II".to_string(),
-        first_aired: NaiveDate::from_ymd(2016, 11, 6),
+        first_aired: NaiveDate::from_ymd_opt(2016, 11, 6).unwrap(),
         network: "BBC One".to_string(),
         slug: "planet-earth-ii".to_string(),
         status: SeriesStatus::Ended,
@@ -122,7 +122,7 @@ lazy_static! {
         aired_season_id: 684701,
         aired_episode_number: 1,
         episode_name: "Islands".to_string(),
-        first_aired: NaiveDate::from_ymd(2016, 11, 6),
+        first_aired: NaiveDate::from_ymd_opt(2016, 11, 6).unwrap(),
         absolute_number: 1,
         series_id: PEII.id,
         imdb_id: "tt5491994".to_string()
@@ -156,7 +156,7 @@ lazy_static! {
     };
     pub static ref RELEASE: TestReleaseDate = TestReleaseDate {
         kind: "release_date".to_string(),
-        date: NaiveDate::from_ymd(1995, 2, 9),
+        date: NaiveDate::from_ymd_opt(1995, 2, 9).unwrap(),
         country: "global".to_string()
     };
     pub static ref TSR_IMDB: TestRemoteID = TestRemoteID {